@@ -1,5 +1,5 @@
 use darling::ast::NestedMeta;
-use darling::{Error, FromMeta};
+use darling::{Error, FromAttributes, FromMeta};
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::parse::{Parse, ParseStream};
@@ -121,7 +121,59 @@ event_handler_args![
 
 #[derive(Debug, FromMeta)]
 struct ComponentArgs {
-    id: String,
+    #[darling(default)]
+    id: Option<String>,
+    #[darling(default)]
+    fallback: bool,
+    #[darling(flatten)]
+    check_args: CheckArgs,
+}
+
+/// `guild_only`, `dm_only`, `owner_only`, `nsfw_only`, `bot_permissions = "..."`
+/// and `checks = [...]`, see `internals::Checks`.
+#[derive(Debug, Default, FromMeta)]
+struct CheckArgs {
+    #[darling(default)]
+    guild_only: bool,
+    #[darling(default)]
+    dm_only: bool,
+    #[darling(default)]
+    owner_only: bool,
+    #[darling(default)]
+    nsfw_only: bool,
+    #[darling(multiple)]
+    bot_permissions: Vec<String>,
+    #[darling(default)]
+    checks: Option<ExprArray>,
+}
+
+impl CheckArgs {
+    fn quote(self) -> darling::Result<proc_macro2::TokenStream> {
+        if self.guild_only && self.dm_only {
+            return Err(Error::custom("`guild_only` and `dm_only` can't be used together"));
+        }
+
+        let CheckArgs {
+            guild_only,
+            dm_only,
+            owner_only,
+            nsfw_only,
+            bot_permissions,
+            checks,
+        } = self;
+        let checks = checks.into_iter().flat_map(|checks| checks.elems);
+
+        Ok(quote! {
+            descord::internals::Checks {
+                guild_only: #guild_only,
+                dm_only: #dm_only,
+                owner_only: #owner_only,
+                nsfw_only: #nsfw_only,
+                bot_permissions: vec![#(#bot_permissions.to_string()),*],
+                custom: vec![#(#checks()),*],
+            }
+        })
+    }
 }
 
 #[proc_macro_attribute]
@@ -145,7 +197,17 @@ pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
         }
     };
 
-    let custom_id = component_args.id.to_string();
+    let checks = match component_args.check_args.quote() {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+
+    let fallback = component_args.fallback;
+    let custom_id = match component_args.id {
+        Some(id) if !fallback => id,
+        None if fallback => String::new(),
+        _ => panic!("Expected either `id = \"...\"` or `fallback`"),
+    };
 
     let param_name = match function.sig.inputs.first().unwrap() {
         syn::FnArg::Typed(x) => match *x.pat {
@@ -156,9 +218,85 @@ pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
         _ => panic!("self???"),
     };
 
-    let let_stmt = quote! {
-        let #param_name = data;
-    };
+    let mut param_names = vec![];
+    let mut param_types = vec![];
+    let mut stmts = vec![];
+    // parameters taken from the interaction itself
+    let mut data_stmts = vec![];
+
+    for param in function.sig.inputs.iter().skip(1) {
+        let syn::FnArg::Typed(param) = param else {
+            panic!("`self` is not allowed");
+        };
+
+        let syn::Pat::Ident(name) = &*param.pat else {
+            panic!("unknown parameter name");
+        };
+
+        let name = &name.ident;
+        if param.attrs.iter().any(|attr| attr.path().is_ident("state")) {
+            let ty = &param.ty;
+            data_stmts.push(quote! {
+                let #name: #ty = match data.state() {
+                    Ok(state) => state,
+                    Err(e) => {
+                        let result: DescordResult = Err(Box::new(e));
+                        return result;
+                    }
+                };
+            });
+
+            continue;
+        }
+
+        let syn::Type::Path(path) = &*param.ty else {
+            panic!("Expected a path found something else");
+        };
+
+        let last_segment = path.path.segments.last().unwrap();
+        if last_segment.ident == "Vec" {
+            let syn::PathArguments::AngleBracketed(args) = &last_segment.arguments else {
+                panic!("Expected `Vec<T>`");
+            };
+
+            let Some(syn::GenericArgument::Type(syn::Type::Path(inner))) = args.args.first() else {
+                panic!("Expected `Vec<T>`");
+            };
+
+            let getter = match inner.path.segments.last().unwrap().ident.to_string().as_str() {
+                "String" => quote! { selected_values },
+                "User" => quote! { selected_users },
+                "Member" => quote! { selected_members },
+                "Role" => quote! { selected_roles },
+                "Channel" => quote! { selected_channels },
+                _ => panic!("Unsupported select menu type, expected `Vec<String>`, `Vec<User>`, `Vec<Member>`, `Vec<Role>` or `Vec<Channel>`"),
+            };
+
+            data_stmts.push(quote! {
+                let #name = data.#getter();
+            });
+
+            continue;
+        }
+
+        if !custom_id.contains(&format!("{{{name}}}")) {
+            panic!("`{name}` is not captured by the component id, expected `{{{name}}}` in `{custom_id}`");
+        }
+
+        let (variant, ty) = match path.path.segments.last().unwrap().ident.to_string().as_str() {
+            "String" => (quote! { String }, type_name!(String)),
+            "isize" => (quote! { Int }, type_name!(Int)),
+            "f64" => (quote! { Number }, type_name!(Number)),
+            "bool" => (quote! { Bool }, type_name!(Bool)),
+            _ => panic!("Unsupported type, component parameters can be `String`, `isize`, `f64` or `bool`"),
+        };
+
+        param_names.push(name.to_string());
+        param_types.push(ty);
+        stmts.push(quote! {
+            let Some(descord::internals::Value::#variant(#name)) = args.next() else { unreachable!() };
+        });
+    }
 
     let expanded = quote! {
         #function_vis fn #function_name() -> descord::internals::ComponentHandler {
@@ -166,9 +304,14 @@ pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
 
             fn f(
                 data: Interaction,
+                args: Vec<internals::Value>,
             ) -> std::pin::Pin<Box<dyn std::future::Future<Output = DescordResult> + Send + 'static>> {
                 Box::pin(async move {
-                    #let_stmt
+                    #(#data_stmts)*
+                    let #param_name = data;
+                    let mut args = args.into_iter();
+                    #(#stmts)*
+                    drop(args);
                     #function_body
                     Ok(())
                 })
@@ -176,7 +319,11 @@ pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
 
             internals::ComponentHandler {
                 id: #custom_id.to_string(),
+                fallback: #fallback,
+                fn_sig: vec![#(#param_types),*],
+                fn_param_names: vec![#(#param_names.to_string()),*],
                 handler_fn: f,
+                checks: #checks,
             }
         }
     };
@@ -184,12 +331,159 @@ pub fn component(args: TokenStream, input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// `#[named]`, `#[flag]`, `#[default = ...]` and constraints on message command parameters
+#[derive(Debug, Default, FromMeta)]
+struct CommandParamArgs {
+    /// The parameter is a `#[derive(CommandArgs)]` struct
+    #[darling(default)]
+    args: bool,
+    #[darling(default)]
+    named: bool,
+    #[darling(default)]
+    flag: bool,
+    #[darling(default)]
+    default: Option<syn::Expr>,
+    #[darling(default)]
+    min_value: Option<syn::Lit>,
+    #[darling(default)]
+    max_value: Option<syn::Lit>,
+    #[darling(default)]
+    min_length: Option<u32>,
+    #[darling(default)]
+    max_length: Option<u32>,
+    #[darling(multiple)]
+    choice: Vec<ChoiceArg>,
+}
+
+impl CommandParamArgs {
+    /// Parses the attributes of a parameter, doc comments are ignored.
+    fn from_attrs(attrs: &[syn::Attribute]) -> darling::Result<Self> {
+        let attrs: Vec<_> = attrs
+            .iter()
+            .filter(|attr| !attr.path().is_ident("doc"))
+            .map(|attr| NestedMeta::Meta(attr.meta.clone()))
+            .collect();
+
+        Self::from_list(&attrs)
+    }
+}
+
+/// Tokens describing a message command parameter, see `internals::Command`.
+struct MessageParam {
+    param_type: proc_macro2::TokenStream,
+    name: String,
+    kind: proc_macro2::TokenStream,
+    type_name: proc_macro2::TokenStream,
+    parser: proc_macro2::TokenStream,
+    optional: bool,
+    constraints: proc_macro2::TokenStream,
+    /// Takes the parsed value out of `args`.
+    value: proc_macro2::TokenStream,
+}
+
+fn message_param(
+    name: &syn::Ident,
+    ty: &syn::Type,
+    attr: CommandParamArgs,
+) -> darling::Result<MessageParam> {
+    let kind = match (attr.named, attr.flag) {
+        (_, true) => quote! { descord::internals::ParamKind::Flag },
+        (true, _) => quote! { descord::internals::ParamKind::Named },
+        _ => quote! { descord::internals::ParamKind::Positional },
+    };
+
+    let constraints = constraints_quote(
+        &attr.min_value,
+        &attr.max_value,
+        attr.min_length,
+        attr.max_length,
+        &attr.choice,
+    )?;
+
+    // flags are `false` unless passed
+    let default = attr
+        .default
+        .or_else(|| attr.flag.then(|| syn::parse_quote! { false }));
+
+    let (inner, parser, optional) = match option_inner(ty) {
+        Some(inner) => {
+            if default.is_some() {
+                return Err(Error::custom("`Option` parameters can't have a default value")
+                    .with_span(ty));
+            }
+
+            (inner, quote! { parse_optional_argument }, true)
+        }
+
+        None => (ty, quote! { parse_argument }, default.is_some()),
+    };
+
+    let value = match default {
+        Some(default) => quote! {
+            match args.next().unwrap() {
+                descord::internals::Value::None => #default,
+                value => value.downcast().unwrap(),
+            }
+        },
+
+        None => quote! {
+            args.next().and_then(descord::internals::Value::downcast).unwrap()
+        },
+    };
+
+    Ok(MessageParam {
+        param_type: quote! { <#inner as descord::internals::FromArgument>::param_type() },
+        name: name.to_string().trim_start_matches("r#").to_string(),
+        kind,
+        type_name: quote! { <#inner as descord::internals::FromArgument>::type_name() },
+        parser: quote! { Some(descord::internals::#parser::<#inner>) },
+        optional,
+        constraints,
+        value,
+    })
+}
+
+/// Returns `T` if the type is `Option<T>`.
+fn option_inner(ty: &syn::Type) -> Option<&syn::Type> {
+    let syn::Type::Path(path) = ty else {
+        return None;
+    };
+
+    let last_segment = path.path.segments.last()?;
+    if last_segment.ident != "Option" {
+        return None;
+    }
+
+    let syn::PathArguments::AngleBracketed(angle_bracketed_data) = &last_segment.arguments else {
+        return None;
+    };
+
+    match angle_bracketed_data.args.first()? {
+        syn::GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
 #[derive(Debug, FromMeta)]
 struct CommandArgs {
+    #[darling(flatten)]
+    check_args: CheckArgs,
     #[darling(default)]
     name: Option<String>,
     #[darling(default)]
+    cooldown: Option<String>,
+    #[darling(default)]
+    bucket: Option<String>,
+    #[darling(default)]
+    burst: Option<u32>,
+    #[darling(default)]
+    timeout: Option<String>,
+    #[darling(default)]
+    aliases: Option<ExprArray>,
+    #[darling(default)]
     prefix: Option<String>,
+    #[darling(default)]
+    category: Option<String>,
     #[darling(multiple)]
     permissions: Vec<String>,
     #[darling(default)]
@@ -321,14 +615,31 @@ pub fn command(args: TokenStream, input: TokenStream) -> TokenStream {
         .description
         .unwrap_or("No description provided".to_string());
 
-    let custom_prefix = command_args.prefix.is_some();
-    let new_name = format!(
-        "{}{}",
-        command_args.prefix.as_ref().unwrap_or(&String::new()),
-        command_args
-            .name
-            .unwrap_or_else(|| function.sig.ident.to_string())
-    );
+    let prefix = option_quote(command_args.prefix.map(|prefix| quote! { #prefix.to_string() }));
+    let category = option_quote(command_args.category.map(|category| quote! { #category.to_string() }));
+    let aliases = match string_array(command_args.aliases) {
+        Ok(aliases) => aliases,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+    let cooldown = match cooldown_quote(
+        command_args.cooldown,
+        command_args.bucket,
+        command_args.burst,
+    ) {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+    let timeout = match timeout_quote(command_args.timeout) {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+    let checks = match command_args.check_args.quote() {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+    let new_name = command_args
+        .name
+        .unwrap_or_else(|| function.sig.ident.to_string());
 
     let function_name = &function.sig.ident;
     let permissions = command_args.permissions;
@@ -369,14 +680,14 @@ pub fn command(args: TokenStream, input: TokenStream) -> TokenStream {
         _ => error(),
     };
 
-    let mut param_types = vec![];
+    let mut params = vec![];
     let mut stmts = vec![];
-    let mut optional_params = vec![];
+    let mut args_struct = None;
 
     let mut stop = false;
-    for (idx, param) in function_params.iter().skip(1).enumerate() {
+    for param in function_params.iter().skip(1) {
         if stop {
-            panic!("`Arg` should be the last parameter");
+            panic!("`Args` and `#[args]` parameters should be the last parameter");
         }
 
         let param = match param {
@@ -388,92 +699,119 @@ pub fn command(args: TokenStream, input: TokenStream) -> TokenStream {
             panic!();
         };
 
-        let type_ = (*param.ty).clone();
-
-        let syn::Type::Path(path) = type_ else {
+        let ty = &*param.ty;
+        let syn::Type::Path(path) = ty else {
             panic!("Expected a path found something else");
         };
 
-        let (name, ty, optional) = match path
-            .path
-            .segments
-            .last()
-            .unwrap()
-            .ident
-            .to_string()
-            .as_str()
-        {
-            "Option" => {
-                let mut inner_type = String::new();
-                match &path.path.segments.last().unwrap().arguments {
-                    syn::PathArguments::AngleBracketed(angle_bracketed_data) => {
-                        for arg in &angle_bracketed_data.args {
-                            if let syn::GenericArgument::Type(syn::Type::Path(type_path)) = arg {
-                                inner_type =
-                                    type_path.path.segments.last().unwrap().ident.to_string();
-                            }
-                        }
-                    }
-                    _ => panic!("Expected AngleBracketed PathArguments"),
-                }
-                match inner_type.as_str() {
-                    "String" => (type_path!(StringOption, name), type_name!(String), true),
+        let param_attr = match CommandParamArgs::from_attrs(&param.attrs) {
+            Ok(v) => v,
+            Err(e) => return TokenStream::from(e.write_errors()),
+        };
 
-                    "isize" => (type_path!(IntOption, name), type_name!(Int), true),
-                    "bool" => (type_path!(BoolOption, name), type_name!(Bool), true),
-                    "Channel" => (type_path!(ChannelOption, name), type_name!(Channel), true),
-                    "User" => (type_path!(UserOption, name), type_name!(User), true),
-                    _ => panic!("Unsupported type"),
-                }
-            }
-            "String" => (type_path!(String, name), type_name!(String), false),
-            "isize" => (type_path!(Int, name), type_name!(Int), false),
-            "bool" => (type_path!(Bool, name), type_name!(Bool), false),
-            "Channel" => (type_path!(Channel, name), type_name!(Channel), false),
-            "User" => (type_path!(User, name), type_name!(User), false),
-            "Args" => {
-                stop = true; // will stop the loop from running again
-                (type_path!(Args, name), type_name!(Args), false)
+        if param_attr.args {
+            stop = true;
+            args_struct = Some(ty.clone());
+            stmts.push(quote! {
+                let #name: #ty = <#ty as descord::internals::CommandArgs>::from_args(&mut __args);
+            });
+
+            continue;
+        }
+
+        if path.path.segments.last().unwrap().ident == "Args" {
+            if param_attr.named || param_attr.flag || param_attr.default.is_some() {
+                panic!("`Args` can't be named, a flag or have a default value");
             }
 
-            _ => panic!("Unsupported type"),
+            stop = true; // will stop the loop from running again
+            params.push(MessageParam {
+                param_type: type_name!(Args),
+                name: name.ident.to_string(),
+                kind: quote! { descord::internals::ParamKind::Positional },
+                type_name: quote! { "text" },
+                parser: quote! { None },
+                optional: false,
+                constraints: quote! { Default::default() },
+                value: quote! {},
+            });
+
+            stmts.push(quote! {
+                let descord::internals::Value::Args(#name) = __args.next().unwrap() else { unreachable!() };
+            });
+
+            continue;
+        }
+
+        let param = match message_param(&name.ident, ty, param_attr) {
+            Ok(v) => v,
+            Err(e) => return TokenStream::from(e.write_errors()),
         };
 
-        optional_params.push(optional);
-        param_types.push(ty);
+        let value = &param.value;
         stmts.push(quote! {
-            let #name = args[#idx].clone() else { unreachable!() };
+            let #name: #ty = {
+                let args = &mut __args;
+                #value
+            };
         });
+        params.push(param);
     }
 
     let mut let_stmts = proc_macro2::TokenStream::new();
     let_stmts.extend(stmts.into_iter());
 
+    let param_types = params.iter().map(|param| &param.param_type);
+    let param_names = params.iter().map(|param| &param.name);
+    let param_kinds = params.iter().map(|param| &param.kind);
+    let type_names = params.iter().map(|param| &param.type_name);
+    let parsers = params.iter().map(|param| &param.parser);
+    let optional_params = params.iter().map(|param| param.optional);
+    let param_constraints = params.iter().map(|param| &param.constraints);
+
+    let add_args = args_struct.map(|ty| {
+        quote! { <#ty as descord::internals::CommandArgs>::add_params(&mut command); }
+    });
+
     let expanded = quote! {
         #function_vis fn #function_name() -> descord::internals::Command {
             use descord::prelude::*;
 
             fn f(
                 #first_param_name: Message,
-                args: Vec<internals::Value>
+                __args: Vec<internals::Value>
             ) -> std::pin::Pin<Box<dyn std::future::Future<Output = DescordResult> + Send + 'static>> {
                 Box::pin(async move {
+                    let mut __args = __args.into_iter();
                     #let_stmts
-                    drop(args);
+                    drop(__args);
                     #function_body
                     Ok(())
                 })
             }
 
-            internals::Command {
+            let mut command = internals::Command {
                 name: String::from(#new_name),
+                aliases: vec![#(#aliases.to_string()),*],
                 fn_sig: vec![#(#param_types),*],
+                fn_param_names: vec![#(#param_names.to_string()),*],
+                fn_param_kinds: vec![#(#param_kinds),*],
+                fn_param_type_names: vec![#(#type_names),*],
+                fn_param_constraints: vec![#(#param_constraints),*],
+                parsers: vec![#(#parsers),*],
                 description: String::from(#description),
                 handler_fn: f,
-                custom_prefix: #custom_prefix,
+                prefix: #prefix,
+                category: #category,
+                cooldown: #cooldown,
+                timeout: #timeout,
+                checks: #checks,
                 optional_params: vec![#(#optional_params),*],
                 permissions: vec![#(#permissions.to_string()),*],
-            }
+            };
+
+            #add_args
+            command
         }
     };
 
@@ -482,12 +820,205 @@ pub fn command(args: TokenStream, input: TokenStream) -> TokenStream {
 
 #[derive(Debug, FromMeta)]
 struct SlashCommandArgs {
+    #[darling(flatten)]
+    check_args: CheckArgs,
     #[darling(default)]
     name: Option<String>,
     #[darling(default)]
+    cooldown: Option<String>,
+    #[darling(default)]
+    bucket: Option<String>,
+    #[darling(default)]
+    burst: Option<u32>,
+    #[darling(default)]
+    timeout: Option<String>,
+    #[darling(default)]
     description: Option<String>,
     #[darling(multiple)]
     permissions: Vec<String>,
+    #[darling(default)]
+    nsfw: bool,
+    #[darling(multiple)]
+    localize: Vec<LocalizeArg>,
+    #[darling(default)]
+    contexts: Option<syn::ExprArray>,
+    #[darling(default)]
+    integration_types: Option<syn::ExprArray>,
+}
+
+/// `cooldown = "5s", bucket = "user", burst = 2`, see `internals::Cooldown`.
+fn cooldown_quote(
+    cooldown: Option<String>,
+    bucket: Option<String>,
+    burst: Option<u32>,
+) -> darling::Result<proc_macro2::TokenStream> {
+    let Some(cooldown) = cooldown else {
+        if bucket.is_some() || burst.is_some() {
+            return Err(Error::custom("`bucket` and `burst` require a `cooldown`"));
+        }
+
+        return Ok(quote! { None });
+    };
+
+    let bucket = match bucket.as_deref().unwrap_or("user") {
+        "user" => quote! { User },
+        "member" => quote! { Member },
+        "channel" => quote! { Channel },
+        "guild" => quote! { Guild },
+        "global" => quote! { Global },
+        bucket => {
+            return Err(Error::custom(format!(
+                "Unknown bucket `{bucket}`, expected one of `user`, `member`, `channel`, `guild` or `global`"
+            )))
+        }
+    };
+    let burst = burst.unwrap_or(1);
+
+    let Some(seconds) = parse_duration(&cooldown) else {
+        return Err(Error::custom(format!(
+            "Invalid cooldown `{cooldown}`, expected something like `5s` or `1m30s`"
+        )));
+    };
+
+    Ok(quote! {
+        Some(descord::internals::Cooldown::new(
+            std::time::Duration::from_secs(#seconds),
+            descord::internals::CooldownBucket::#bucket,
+            #burst,
+        ))
+    })
+}
+
+/// `timeout = "30s"`, overrides the client's handler timeout.
+fn timeout_quote(timeout: Option<String>) -> darling::Result<proc_macro2::TokenStream> {
+    let Some(timeout) = timeout else {
+        return Ok(quote! { None });
+    };
+
+    let Some(seconds) = parse_duration(&timeout) else {
+        return Err(Error::custom(format!(
+            "Invalid timeout `{timeout}`, expected something like `30s` or `2m`"
+        )));
+    };
+
+    Ok(quote! { Some(std::time::Duration::from_secs(#seconds)) })
+}
+
+/// Parses durations like `90`, `45s`, `10m` or `1h30m` into seconds,
+/// the same way `internals::parse_duration` does at runtime.
+fn parse_duration(duration: &str) -> Option<u64> {
+    if let Ok(seconds) = duration.parse::<u64>() {
+        return Some(seconds);
+    }
+
+    let mut total: u64 = 0;
+    let mut rest = duration.to_lowercase();
+
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let amount = rest[..digits].parse::<u64>().ok()?;
+        let unit_len = rest[digits..]
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len() - digits);
+
+        let seconds = match &rest[digits..digits + unit_len] {
+            "s" | "sec" | "secs" => 1,
+            "m" | "min" | "mins" => 60,
+            "h" | "hr" | "hrs" => 60 * 60,
+            "d" | "day" | "days" => 24 * 60 * 60,
+            "w" | "week" | "weeks" => 7 * 24 * 60 * 60,
+            _ => return None,
+        };
+
+        total = total.checked_add(amount.checked_mul(seconds)?)?;
+        rest.drain(..digits + unit_len);
+    }
+
+    Some(total)
+}
+
+/// Parses a `["a", "b"]` array of string literals.
+fn string_array(array: Option<ExprArray>) -> darling::Result<Vec<String>> {
+    let Some(array) = array else {
+        return Ok(vec![]);
+    };
+
+    array
+        .elems
+        .iter()
+        .map(|elem| match elem {
+            syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit),
+                ..
+            }) => Ok(lit.value()),
+            _ => Err(Error::custom("Expected a string literal").with_span(elem)),
+        })
+        .collect()
+}
+
+/// Maps `[guild, bot_dm]` style arrays to the variants of the given enum.
+fn enum_array_quote(
+    array: Option<syn::ExprArray>,
+    enum_name: &str,
+    variants: &[(&str, &str)],
+) -> darling::Result<proc_macro2::TokenStream> {
+    let enum_name = Ident::new(enum_name, proc_macro2::Span::call_site());
+    let mut items = vec![];
+
+    for elem in array.map(|array| array.elems).into_iter().flatten() {
+        let syn::Expr::Path(ref path) = elem else {
+            return Err(Error::custom("expected an identifier").with_span(&elem));
+        };
+
+        let name = path
+            .path
+            .get_ident()
+            .map(|ident| ident.to_string())
+            .unwrap_or_default();
+
+        let Some((_, variant)) = variants.iter().find(|(key, _)| *key == name) else {
+            let expected = variants.iter().map(|(key, _)| *key).collect::<Vec<_>>();
+            return Err(
+                Error::custom(format!("expected one of {expected:?}")).with_span(&elem)
+            );
+        };
+
+        let variant = Ident::new(variant, proc_macro2::Span::call_site());
+        items.push(quote! { #enum_name::#variant });
+    }
+
+    Ok(quote! { vec![#(#items),*] })
+}
+
+/// `#[localize(locale = "de", name = "...", description = "...")]`
+#[derive(Debug, FromMeta)]
+struct LocalizeArg {
+    locale: String,
+    #[darling(default)]
+    name: Option<String>,
+    #[darling(default)]
+    description: Option<String>,
+}
+
+fn localizations_quote(localize: &[LocalizeArg]) -> proc_macro2::TokenStream {
+    let names = localize.iter().filter_map(|LocalizeArg { locale, name, .. }| {
+        let name = name.as_ref()?;
+        Some(quote! { (String::from(#locale), String::from(#name)) })
+    });
+
+    let descriptions = localize
+        .iter()
+        .filter_map(|LocalizeArg { locale, description, .. }| {
+            let description = description.as_ref()?;
+            Some(quote! { (String::from(#locale), String::from(#description)) })
+        });
+
+    quote! {
+        internals::Localizations {
+            names: [#(#names),*].into_iter().collect(),
+            descriptions: [#(#descriptions),*].into_iter().collect(),
+        }
+    }
 }
 
 #[derive(Debug, FromMeta)]
@@ -497,6 +1028,99 @@ struct SlashOptionArgs {
     #[darling(default)]
     rename: Option<String>,
     autocomplete: Option<syn::ExprPath>,
+    #[darling(default)]
+    min_value: Option<syn::Lit>,
+    #[darling(default)]
+    max_value: Option<syn::Lit>,
+    #[darling(default)]
+    min_length: Option<u32>,
+    #[darling(default)]
+    max_length: Option<u32>,
+    #[darling(multiple)]
+    choice: Vec<ChoiceArg>,
+    #[darling(multiple)]
+    localize: Vec<LocalizeArg>,
+}
+
+/// `#[choice = "value"]` or `#[choice(name = "Display name", value = "value")]`
+#[derive(Debug)]
+struct ChoiceArg {
+    name: String,
+    value: String,
+}
+
+impl FromMeta for ChoiceArg {
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        let value = lit_to_string(value)?;
+        Ok(Self {
+            name: value.clone(),
+            value,
+        })
+    }
+
+    fn from_list(items: &[NestedMeta]) -> darling::Result<Self> {
+        #[derive(FromMeta)]
+        struct Inner {
+            name: String,
+            value: syn::Lit,
+        }
+
+        let Inner { name, value } = Inner::from_list(items)?;
+        Ok(Self {
+            name,
+            value: lit_to_string(&value)?,
+        })
+    }
+}
+
+fn lit_to_string(lit: &syn::Lit) -> darling::Result<String> {
+    match lit {
+        syn::Lit::Str(s) => Ok(s.value()),
+        syn::Lit::Int(i) => Ok(i.base10_digits().to_string()),
+        syn::Lit::Float(f) => Ok(f.base10_digits().to_string()),
+        _ => Err(Error::unexpected_lit_type(lit)),
+    }
+}
+
+fn lit_to_f64(lit: &syn::Lit) -> darling::Result<f64> {
+    match lit {
+        syn::Lit::Int(i) => i.base10_parse().map_err(Error::from),
+        syn::Lit::Float(f) => f.base10_parse().map_err(Error::from),
+        _ => Err(Error::unexpected_lit_type(lit)),
+    }
+}
+
+fn option_quote<T: quote::ToTokens>(value: Option<T>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => quote! { Some(#value) },
+        None => quote! { None },
+    }
+}
+
+fn constraints_quote(
+    min_value: &Option<syn::Lit>,
+    max_value: &Option<syn::Lit>,
+    min_length: Option<u32>,
+    max_length: Option<u32>,
+    choices: &[ChoiceArg],
+) -> darling::Result<proc_macro2::TokenStream> {
+    let min_value = option_quote(min_value.as_ref().map(lit_to_f64).transpose()?);
+    let max_value = option_quote(max_value.as_ref().map(lit_to_f64).transpose()?);
+    let min_length = option_quote(min_length);
+    let max_length = option_quote(max_length);
+    let choices = choices.iter().map(|ChoiceArg { name, value }| {
+        quote! { (String::from(#name), String::from(#value)) }
+    });
+
+    Ok(quote! {
+        descord::internals::OptionConstraints {
+            min_value: #min_value,
+            max_value: #max_value,
+            min_length: #min_length,
+            max_length: #max_length,
+            choices: vec![#(#choices),*],
+        }
+    })
 }
 
 #[proc_macro_attribute]
@@ -522,6 +1146,47 @@ pub fn slash(args: TokenStream, input: TokenStream) -> TokenStream {
     };
 
     let permissions = slash_command_args.permissions;
+    let nsfw = slash_command_args.nsfw;
+    let cooldown = match cooldown_quote(
+        slash_command_args.cooldown,
+        slash_command_args.bucket,
+        slash_command_args.burst,
+    ) {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+    let timeout = match timeout_quote(slash_command_args.timeout) {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+    let checks = match slash_command_args.check_args.quote() {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+    let localizations = localizations_quote(&slash_command_args.localize);
+    let contexts = match enum_array_quote(
+        slash_command_args.contexts,
+        "InteractionContextType",
+        &[
+            ("guild", "Guild"),
+            ("bot_dm", "BotDm"),
+            ("private_channel", "PrivateChannel"),
+        ],
+    ) {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+    let integration_types = match enum_array_quote(
+        slash_command_args.integration_types,
+        "IntegrationType",
+        &[
+            ("guild_install", "GuildInstall"),
+            ("user_install", "UserInstall"),
+        ],
+    ) {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
 
     let new_name = slash_command_args
         .name
@@ -572,13 +1237,15 @@ pub fn slash(args: TokenStream, input: TokenStream) -> TokenStream {
     let mut param_descriptions = vec![];
     let mut param_autocomplete = vec![];
     let mut param_renames = vec![];
+    let mut param_constraints = vec![];
+    let mut param_localizations = vec![];
     let mut optional_params = vec![];
     let mut stmts: Vec<proc_macro2::TokenStream> = vec![];
 
-    let stop = false;
+    let mut args_struct = None;
     for (idx, param) in function_params.iter().skip(1).enumerate() {
-        if stop {
-            panic!("`Arg` should be the last parameter");
+        if args_struct.is_some() {
+            panic!("`#[args]` should be the last parameter");
         }
 
         let mut param = match param {
@@ -591,6 +1258,18 @@ pub fn slash(args: TokenStream, input: TokenStream) -> TokenStream {
             panic!();
         };
 
+        if param.attrs.iter().any(|attr| attr.path().is_ident("args")) {
+            let ty = &param.ty;
+            args_struct = Some(ty.clone());
+            stmts.push(quote! {
+                let #name: #ty = <#ty as descord::internals::CommandArgs>::from_options(
+                    &mut __args[#idx..].to_vec().into_iter(),
+                );
+            });
+
+            continue;
+        }
+
         param_names.push(quote! { stringify!(#name).to_string() });
 
         let attrs: Vec<_> = param
@@ -606,8 +1285,12 @@ pub fn slash(args: TokenStream, input: TokenStream) -> TokenStream {
 
         param_autocomplete.push(if let Some(autocomplete_fn) = param_attr.autocomplete {
             quote! { Some(
-                |query: String| Box::pin(async move {
-                    #autocomplete_fn(query).await.into_iter().take(25).collect::<Vec<_>>()
+                |context: internals::AutoCompleteContext| Box::pin(async move {
+                    #autocomplete_fn(context.into())
+                        .await
+                        .into_iter()
+                        .map(Into::<InteractionAutoCompleteChoice>::into)
+                        .collect::<Vec<_>>()
                 })
             ) }
         } else {
@@ -620,6 +1303,21 @@ pub fn slash(args: TokenStream, input: TokenStream) -> TokenStream {
             quote! { None }
         });
 
+        param_constraints.push(
+            match constraints_quote(
+                &param_attr.min_value,
+                &param_attr.max_value,
+                param_attr.min_length,
+                param_attr.max_length,
+                &param_attr.choice,
+            ) {
+                Ok(v) => v,
+                Err(e) => return TokenStream::from(e.write_errors()),
+            },
+        );
+
+        param_localizations.push(localizations_quote(&param_attr.localize));
+
         let doc = param_attr
             .doc
             .map(|i| i.trim().to_string())
@@ -656,6 +1354,7 @@ pub fn slash(args: TokenStream, input: TokenStream) -> TokenStream {
                 match inner_type.as_str() {
                     "String" => (type_path!(StringOption, name), type_name!(String), true),
                     "isize" => (type_path!(IntOption, name), type_name!(Int), true),
+                    "f64" => (type_path!(NumberOption, name), type_name!(Number), true),
                     "bool" => (type_path!(BoolOption, name), type_name!(Bool), true),
                     "Channel" => (type_path!(ChannelOption, name), type_name!(Channel), true),
                     "User" => (type_path!(UserOption, name), type_name!(User), true),
@@ -664,6 +1363,7 @@ pub fn slash(args: TokenStream, input: TokenStream) -> TokenStream {
             }
             "String" => (type_path!(String, name), type_name!(String), false),
             "isize" => (type_path!(Int, name), type_name!(Int), false),
+            "f64" => (type_path!(Number, name), type_name!(Number), false),
             "bool" => (type_path!(Bool, name), type_name!(Bool), false),
             "Channel" => (type_path!(Channel, name), type_name!(Channel), false),
             "User" => (type_path!(User, name), type_name!(User), false),
@@ -673,40 +1373,327 @@ pub fn slash(args: TokenStream, input: TokenStream) -> TokenStream {
         optional_params.push(optional);
         param_types.push(ty);
         stmts.push(quote! {
-            let #name = args[#idx].clone() else { unreachable!() };
+            let #name = __args[#idx].clone() else { unreachable!() };
         });
     }
 
     let mut let_stmts = proc_macro2::TokenStream::new();
     let_stmts.extend(stmts.into_iter());
 
+    let add_args = args_struct.map(|ty| {
+        let message = format!(
+            "`{}` is `#[command_args(message_only)]` and can't be used by slash commands",
+            quote! { #ty }.to_string().replace(' ', "")
+        );
+
+        let check = quote::quote_spanned! {syn::spanned::Spanned::span(&ty)=>
+            const _: () = assert!(!<#ty as descord::internals::CommandArgs>::MESSAGE_ONLY, #message);
+        };
+
+        quote! {
+            #check
+            <#ty as descord::internals::CommandArgs>::add_options(&mut command);
+        }
+    });
+
     let expanded = quote! {
         #function_vis fn #function_name() -> descord::internals::SlashCommand {
             use descord::prelude::*;
 
             fn f(
                 #first_param_name: descord::models::interaction::Interaction,
-                args: Vec<internals::Value>
+                __args: Vec<internals::Value>
             ) -> std::pin::Pin<Box<dyn std::future::Future<Output = DescordResult> + Send + 'static>> {
                 Box::pin(async move {
                     #let_stmts
-                    drop(args);
+                    drop(__args);
                     #function_body
                     Ok(())
                 })
             }
 
-            internals::SlashCommand {
+            let mut command = internals::SlashCommand {
                 name: String::from(#new_name),
                 description: String::from(#description),
+                localizations: #localizations,
+                nsfw: #nsfw,
+                contexts: #contexts,
+                integration_types: #integration_types,
                 fn_sig: vec![#(#param_types),*],
                 fn_param_names: vec![#(#param_names),*],
                 fn_param_descriptions: vec![#(#param_descriptions.to_string()),*],
                 fn_param_renames: vec![#(#param_renames),*],
                 fn_param_autocomplete: vec![#(#param_autocomplete),*],
+                fn_param_constraints: vec![#(#param_constraints),*],
+                fn_param_localizations: vec![#(#param_localizations),*],
                 optional_params: vec![#(#optional_params),*],
                 permissions: vec![#(#permissions.to_string()),*],
                 handler_fn: f,
+                cooldown: #cooldown,
+                timeout: #timeout,
+                checks: #checks,
+            };
+
+            #add_args
+            command
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+#[derive(Debug, FromMeta)]
+struct HybridArgs {
+    #[darling(flatten)]
+    check_args: CheckArgs,
+    #[darling(default)]
+    name: Option<String>,
+    #[darling(default)]
+    cooldown: Option<String>,
+    #[darling(default)]
+    bucket: Option<String>,
+    #[darling(default)]
+    burst: Option<u32>,
+    #[darling(default)]
+    timeout: Option<String>,
+    #[darling(default)]
+    aliases: Option<ExprArray>,
+    #[darling(default)]
+    prefix: Option<String>,
+    #[darling(default)]
+    category: Option<String>,
+    #[darling(multiple)]
+    permissions: Vec<String>,
+    #[darling(default)]
+    description: Option<String>,
+    #[darling(default)]
+    nsfw: bool,
+    #[darling(multiple)]
+    localize: Vec<LocalizeArg>,
+    #[darling(default)]
+    contexts: Option<syn::ExprArray>,
+    #[darling(default)]
+    integration_types: Option<syn::ExprArray>,
+}
+
+#[proc_macro_attribute]
+pub fn hybrid(args: TokenStream, input: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(input as ItemFn);
+
+    if function.sig.asyncness.is_none() {
+        panic!("Function marked with `#[descord::hybrid(...)]` should be async");
+    }
+
+    let attr_args = match NestedMeta::parse_meta_list(args.into()) {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(Error::from(e).write_errors());
+        }
+    };
+
+    let hybrid_args: HybridArgs = match HybridArgs::from_list(&attr_args) {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(Error::from(e).write_errors());
+        }
+    };
+
+    let name = hybrid_args
+        .name
+        .unwrap_or_else(|| function.sig.ident.to_string());
+    let description = hybrid_args
+        .description
+        .unwrap_or("No description provided".to_string());
+    let prefix = hybrid_args.prefix.map(|prefix| quote! { prefix = #prefix, });
+    let aliases = hybrid_args.aliases.map(|aliases| quote! { aliases = #aliases, });
+    let category = hybrid_args.category.map(|category| quote! { category = #category, });
+    let cooldown = [
+        hybrid_args.cooldown.map(|cooldown| quote! { cooldown = #cooldown, }),
+        hybrid_args.bucket.map(|bucket| quote! { bucket = #bucket, }),
+        hybrid_args.burst.map(|burst| quote! { burst = #burst, }),
+    ];
+    let timeout = hybrid_args.timeout.map(|timeout| quote! { timeout = #timeout, });
+    let permissions = hybrid_args.permissions;
+
+    // only slash commands have these
+    let nsfw = hybrid_args.nsfw.then(|| quote! { nsfw, });
+    let localize = hybrid_args.localize.iter().map(
+        |LocalizeArg {
+             locale,
+             name,
+             description,
+         }| {
+            let name = name.as_ref().map(|name| quote! { , name = #name });
+            let description = description
+                .as_ref()
+                .map(|description| quote! { , description = #description });
+
+            quote! { localize(locale = #locale #name #description), }
+        },
+    );
+    let contexts = hybrid_args.contexts.map(|contexts| quote! { contexts = #contexts, });
+    let integration_types = hybrid_args
+        .integration_types
+        .map(|types| quote! { integration_types = #types, });
+
+    let checks = match hybrid_args.check_args.quote() {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+
+    let function_name = &function.sig.ident;
+    let function_vis = function.vis;
+    let mut function_body = function.block;
+    let mut visitor = ReturnVisitor;
+    visit_mut::visit_block_mut(&mut visitor, &mut function_body);
+
+    let error = || -> ! { panic!("Expected `descord::prelude::HybridContext` as the first argument") };
+    let mut params = function.sig.inputs.into_iter();
+    let first_param_pat = match params.next() {
+        Some(syn::FnArg::Typed(param)) => {
+            match *param.ty {
+                syn::Type::Path(ref path) if path.path.is_ident("HybridContext") => {}
+                _ => error(),
+            }
+
+            param.pat
+        }
+
+        Some(_) => panic!("`self` is not allowed"),
+        None => error(),
+    };
+
+    let mut names = vec![];
+    let mut run_params = vec![];
+    let mut command_params = vec![];
+    let mut slash_params = vec![];
+    let mut slash_values = vec![];
+
+    for param in params {
+        let syn::FnArg::Typed(param) = param else {
+            panic!("`self` is not allowed");
+        };
+
+        let syn::Pat::Ident(ref name) = *param.pat else {
+            panic!("unknown param name");
+        };
+
+        let is_attr = |attr: &syn::Attribute, idents: &[&str]| {
+            idents.iter().any(|ident| attr.path().is_ident(ident))
+        };
+
+        if let Some(attr) = param
+            .attrs
+            .iter()
+            .find(|attr| is_attr(attr, &["named", "flag", "default"]))
+        {
+            panic!(
+                "`#[{}]` on `{}` is not supported by slash commands, use a `#[derive(CommandArgs)]` struct instead",
+                attr.path().get_ident().unwrap(),
+                name.ident
+            );
+        }
+
+        let is_args = param.attrs.iter().any(|attr| attr.path().is_ident("args"));
+        let mut command_param = param.clone();
+        command_param.attrs.retain(|attr| {
+            is_attr(
+                attr,
+                &[
+                    "doc", "args", "min_value", "max_value", "min_length", "max_length", "choice",
+                ],
+            )
+        });
+
+        let mut run_param = param.clone();
+        run_param.attrs.clear();
+
+        // slash command options are bound by reference
+        let ident = &name.ident;
+        slash_values.push(if is_args {
+            quote! { #ident }
+        } else {
+            quote! { #ident.clone() }
+        });
+
+        names.push(ident.clone());
+        run_params.push(run_param);
+        command_params.push(command_param);
+        slash_params.push(param);
+    }
+
+    let expanded = quote! {
+        #function_vis fn #function_name() -> descord::internals::HybridCommand {
+            use descord::prelude::*;
+
+            async fn run(#first_param_pat: HybridContext, #(#run_params),*) -> DescordResult {
+                #function_body
+                Ok(())
+            }
+
+            #[descord::command(name = #name, description = #description, #prefix #aliases #category #(#cooldown)* #timeout #(permissions = #permissions),*)]
+            async fn __message(__msg: Message, #(#command_params),*) {
+                run(__msg.into(), #(#names),*).await?;
+            }
+
+            #[descord::slash(name = #name, description = #description, #nsfw #(#localize)* #contexts #integration_types #(#cooldown)* #timeout #(permissions = #permissions),*)]
+            async fn __slash(__interaction: Interaction, #(#slash_params),*) {
+                run(__interaction.into(), #(#slash_values),*).await?;
+            }
+
+            let mut command = __message();
+            let mut slash_command = __slash();
+
+            // both share the same cooldown
+            slash_command.cooldown = command.cooldown.clone();
+            command.checks = #checks;
+            slash_command.checks = command.checks.clone();
+
+            internals::HybridCommand {
+                command,
+                slash_command,
+            }
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+#[proc_macro_attribute]
+pub fn check(_args: TokenStream, input: TokenStream) -> TokenStream {
+    let function = parse_macro_input!(input as ItemFn);
+
+    if function.sig.asyncness.is_none() {
+        panic!("Function marked with `#[descord::check]` should be async");
+    }
+
+    if function.sig.output == syn::ReturnType::Default {
+        panic!("Function marked with `#[descord::check]` should return `bool` or `Result<(), E>`");
+    }
+
+    let function_vis = function.vis;
+    let function_name = &function.sig.ident;
+    let function_params = &function.sig.inputs;
+    let function_output = &function.sig.output;
+    let function_body = function.block;
+
+    let expanded = quote! {
+        #function_vis fn #function_name() -> descord::internals::Check {
+            use descord::prelude::*;
+
+            async fn __check(#function_params) #function_output #function_body
+
+            fn f(
+                ctx: HybridContext,
+            ) -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<(), String>> + Send + 'static>> {
+                Box::pin(async move {
+                    descord::internals::CheckResult::into_result(__check(ctx).await)
+                })
+            }
+
+            internals::Check {
+                name: stringify!(#function_name).to_string(),
+                handler_fn: f,
             }
         }
     };
@@ -714,6 +1701,211 @@ pub fn slash(args: TokenStream, input: TokenStream) -> TokenStream {
     TokenStream::from(expanded)
 }
 
+/// `#[command_args(...)]` on a `#[derive(CommandArgs)]` struct
+#[derive(Debug, Default, FromAttributes)]
+#[darling(attributes(command_args))]
+struct CommandArgsStructArgs {
+    /// The struct is only used by message commands, so fields can be any argument type
+    #[darling(default)]
+    message_only: bool,
+}
+
+#[proc_macro_derive(
+    CommandArgs,
+    attributes(command_args, named, flag, default, min_value, max_value, min_length, max_length, choice)
+)]
+pub fn command_args(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    let struct_name = &input.ident;
+
+    let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &input.data
+    else {
+        panic!("`CommandArgs` can only be derived for structs with named fields");
+    };
+
+    let struct_args = match CommandArgsStructArgs::from_attributes(&input.attrs) {
+        Ok(v) => v,
+        Err(e) => return TokenStream::from(e.write_errors()),
+    };
+    let message_only = struct_args.message_only;
+
+    let mut add_params = vec![];
+    let mut from_args = vec![];
+    let mut add_options = vec![];
+    let mut from_options = vec![];
+
+    for field in &fields.named {
+        let name = field.ident.as_ref().unwrap();
+        let ty = &field.ty;
+
+        let field_attr = match CommandParamArgs::from_attrs(&field.attrs) {
+            Ok(v) => v,
+            Err(e) => return TokenStream::from(e.write_errors()),
+        };
+
+        if field_attr.args {
+            panic!("`#[args]` can't be used on fields");
+        }
+
+        let description = field
+            .attrs
+            .iter()
+            .filter_map(|attr| match &attr.meta {
+                syn::Meta::NameValue(doc) if doc.path.is_ident("doc") => {
+                    lit_from_expr(&doc.value).and_then(|lit| lit_to_string(&lit).ok())
+                }
+
+                _ => None,
+            })
+            .map(|line| line.trim().to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let description = if description.is_empty() {
+            String::from("...")
+        } else {
+            description
+        };
+
+        let default = field_attr.default.clone();
+        let is_flag = field_attr.flag;
+
+        let param = match message_param(name, ty, field_attr) {
+            Ok(v) => v,
+            Err(e) => return TokenStream::from(e.write_errors()),
+        };
+
+        let MessageParam {
+            param_type,
+            name: param_name,
+            kind,
+            type_name,
+            parser,
+            optional,
+            constraints,
+            value,
+        } = param;
+
+        add_params.push(quote! {
+            command.fn_sig.push(#param_type);
+            command.fn_param_names.push(String::from(#param_name));
+            command.fn_param_kinds.push(#kind);
+            command.fn_param_type_names.push(#type_name);
+            command.fn_param_constraints.push(#constraints);
+            command.parsers.push(#parser);
+            command.optional_params.push(#optional);
+        });
+
+        from_args.push(quote! { #name: #value, });
+
+        if message_only {
+            continue;
+        }
+
+        // slash commands only support some types
+        let (inner, is_option) = match option_inner(ty) {
+            Some(inner) => (inner, true),
+            None => (ty, false),
+        };
+
+        let supported = match inner {
+            syn::Type::Path(path) => path.path.segments.last().is_some_and(|segment| {
+                ["String", "isize", "f64", "bool", "Channel", "User"]
+                    .iter()
+                    .any(|supported| segment.ident == supported)
+            }),
+            _ => false,
+        };
+
+        if !supported {
+            let type_name = quote! { #ty }.to_string().replace(' ', "");
+            return TokenStream::from(
+                Error::custom(format!(
+                    "`{type_name}` can't be a slash command option, expected `String`, `isize`, `f64`, `bool`, `Channel` or `User`, or mark the struct `#[command_args(message_only)]`"
+                ))
+                .with_span(ty)
+                .write_errors(),
+            );
+        }
+
+        add_options.push(quote! {
+            command.fn_sig.push(<#inner as descord::internals::OptionValue>::PARAM_TYPE);
+            command.fn_param_names.push(String::from(#param_name));
+            command.fn_param_descriptions.push(String::from(#description));
+            command.fn_param_renames.push(None);
+            command.fn_param_autocomplete.push(None);
+            command.fn_param_constraints.push(#constraints);
+            command.fn_param_localizations.push(Default::default());
+            command.optional_params.push(#optional);
+        });
+
+        let default = default.or_else(|| is_flag.then(|| syn::parse_quote! { false }));
+        let value = quote! {
+            <#inner as descord::internals::OptionValue>::from_value(args.next().unwrap())
+        };
+
+        from_options.push(match default {
+            Some(default) => quote! { #name: #value.unwrap_or_else(|| #default), },
+            None if is_option => quote! { #name: #value, },
+            None => quote! { #name: #value.unwrap(), },
+        });
+    }
+
+    // slash commands reject message only structs at compile time, see `#[slash]`
+    let slash_impl = if message_only {
+        quote! {
+            const MESSAGE_ONLY: bool = true;
+
+            fn add_options(_: &mut descord::internals::SlashCommand) {
+                unreachable!("`{}` is message only", stringify!(#struct_name))
+            }
+
+            fn from_options(_: &mut impl Iterator<Item = descord::internals::Value>) -> Self {
+                unreachable!("`{}` is message only", stringify!(#struct_name))
+            }
+        }
+    } else {
+        quote! {
+            #[allow(unused_variables)]
+            fn add_options(command: &mut descord::internals::SlashCommand) {
+                #(#add_options)*
+            }
+
+            #[allow(unused_variables)]
+            fn from_options(args: &mut impl Iterator<Item = descord::internals::Value>) -> Self {
+                Self { #(#from_options)* }
+            }
+        }
+    };
+
+    let expanded = quote! {
+        impl descord::internals::CommandArgs for #struct_name {
+            fn add_params(command: &mut descord::internals::Command) {
+                #(#add_params)*
+            }
+
+            fn from_args(args: &mut impl Iterator<Item = descord::internals::Value>) -> Self {
+                Self { #(#from_args)* }
+            }
+
+            #slash_impl
+        }
+    };
+
+    TokenStream::from(expanded)
+}
+
+/// Returns the literal of a `#[doc = "..."]` style attribute value.
+fn lit_from_expr(expr: &syn::Expr) -> Option<syn::Lit> {
+    match expr {
+        syn::Expr::Lit(lit) => Some(lit.lit.clone()),
+        _ => None,
+    }
+}
+
 /// Usage: `register_all_commands!(client => ["src/commands.rs", "src/commands2.rs"]);`
 /// Where `client` is the client object and the array is the list of files to search for commands.
 /// If the array is empty, it will recursively search for files in the `src` directory.
@@ -749,6 +1941,7 @@ pub fn register_all_commands(input: TokenStream) -> TokenStream {
     };
 
     let mut commands = Vec::new();
+    let mut hybrids = Vec::new();
 
     for path in &paths {
         let items = syn::parse_file(&std::fs::read_to_string(&path).unwrap())
@@ -764,13 +1957,20 @@ pub fn register_all_commands(input: TokenStream) -> TokenStream {
                         .map_or(false, |seg| seg.ident == "command")
                 }) {
                     commands.push(function.sig.ident.clone());
+                } else if function.attrs.iter().any(|attr| {
+                    attr.path()
+                        .segments
+                        .last()
+                        .is_some_and(|seg| seg.ident == "hybrid")
+                }) {
+                    hybrids.push(function.sig.ident.clone());
                 }
             }
         }
     }
 
     let expanded = quote! {
-        #client_obj.register_commands(vec![#(#commands()),*]);
+        #client_obj.register_commands(vec![#(#commands(),)* #(#hybrids().command,)*]);
     };
 
     TokenStream::from(expanded)
@@ -808,6 +2008,7 @@ pub fn register_all_slash_commands(input: TokenStream) -> TokenStream {
     };
 
     let mut commands = Vec::new();
+    let mut hybrids = Vec::new();
 
     for path in &paths {
         let items = syn::parse_file(&std::fs::read_to_string(&path).unwrap())
@@ -823,13 +2024,20 @@ pub fn register_all_slash_commands(input: TokenStream) -> TokenStream {
                         .map_or(false, |seg| seg.ident == "slash")
                 }) {
                     commands.push(function.sig.ident.clone());
+                } else if function.attrs.iter().any(|attr| {
+                    attr.path()
+                        .segments
+                        .last()
+                        .is_some_and(|seg| seg.ident == "hybrid")
+                }) {
+                    hybrids.push(function.sig.ident.clone());
                 }
             }
         }
     }
 
     let expanded = quote! {
-        #client_obj.register_slash_commands(vec![#(#commands()),*]).await;
+        #client_obj.register_slash_commands(vec![#(#commands(),)* #(#hybrids().slash_command,)*]).await;
     };
 
     TokenStream::from(expanded)
@@ -935,6 +2143,7 @@ pub fn register_all(input: TokenStream) -> TokenStream {
     let mut commands = Vec::new();
     let mut slash_commands = Vec::new();
     let mut components = Vec::new();
+    let mut hybrids = Vec::new();
 
     for path in &paths {
         let items = syn::parse_file(&std::fs::read_to_string(&path).unwrap())
@@ -971,6 +2180,13 @@ pub fn register_all(input: TokenStream) -> TokenStream {
                         .map_or(false, |seg| seg.ident == "component")
                 }) {
                     components.push(function.sig.ident.clone());
+                } else if function.attrs.iter().any(|attr| {
+                    attr.path()
+                        .segments
+                        .last()
+                        .is_some_and(|seg| seg.ident == "hybrid")
+                }) {
+                    hybrids.push(function.sig.ident.clone());
                 }
             }
         }
@@ -978,8 +2194,8 @@ pub fn register_all(input: TokenStream) -> TokenStream {
 
     let expanded = quote! {
         #client_obj.register_events(vec![#(#events()),*]);
-        #client_obj.register_commands(vec![#(#commands()),*]);
-        #client_obj.register_slash_commands(vec![#(#slash_commands()),*]).await;
+        #client_obj.register_commands(vec![#(#commands(),)* #(#hybrids().command,)*]);
+        #client_obj.register_slash_commands(vec![#(#slash_commands(),)* #(#hybrids().slash_command,)*]).await;
         #client_obj.register_component_callbacks(vec![#(#components()),*]);
     };
 
@@ -1,12 +1,15 @@
 use std::num::NonZeroUsize;
+use std::sync::Arc;
 
 use lru::LruCache;
 use tokio::sync::Mutex;
 
 use crate::consts::{
-    CHANNEL_CACHE_SIZE, GUILD_CACHE_SIZE, MESSAGE_CACHE_SIZE, RATE_LIMITS_CACHE_SIZE,
-    ROLE_CACHE_SIZE,
+    CHANNEL_CACHE_SIZE, COMMAND_REPLIES_CACHE_SIZE, COMPONENT_STATE_CACHE_SIZE, GUILD_CACHE_SIZE,
+    INTERACTION_RESPONSES_CACHE_SIZE, MESSAGE_CACHE_SIZE, RATE_LIMITS_CACHE_SIZE, ROLE_CACHE_SIZE,
 };
+use crate::internals::CommandReply;
+use crate::models::interaction::ResponseState;
 use crate::prelude::Role;
 use crate::prelude::{Channel, Guild, Message};
 
@@ -29,4 +32,13 @@ lazy_static::lazy_static! {
         = Mutex::new(LruCache::new(NonZeroUsize::new(RATE_LIMITS_CACHE_SIZE).unwrap()));
     pub(crate) static ref CHANNEL_CACHE: Mutex<LruCache<String, Channel>>
         = Mutex::new(LruCache::new(NonZeroUsize::new(CHANNEL_CACHE_SIZE).unwrap()));
+    // component state too large for the custom id
+    pub(crate) static ref COMPONENT_STATE_STORE: std::sync::Mutex<LruCache<u64, Vec<u8>>>
+        = std::sync::Mutex::new(LruCache::new(NonZeroUsize::new(COMPONENT_STATE_CACHE_SIZE).unwrap()));
+    // interaction id -> how it was responded to
+    pub(crate) static ref INTERACTION_RESPONSES: std::sync::Mutex<LruCache<String, Arc<Mutex<ResponseState>>>>
+        = std::sync::Mutex::new(LruCache::new(NonZeroUsize::new(INTERACTION_RESPONSES_CACHE_SIZE).unwrap()));
+    // message id -> when it was sent and the command's reply, for re-running edited commands
+    pub(crate) static ref COMMAND_REPLIES: std::sync::Mutex<LruCache<String, CommandReply>>
+        = std::sync::Mutex::new(LruCache::new(NonZeroUsize::new(COMMAND_REPLIES_CACHE_SIZE).unwrap()));
 }
@@ -23,20 +23,27 @@ lazy_static::lazy_static! {
     pub(crate) static ref SESSION_ID: Mutex<Option<String>> = Mutex::new(None);
     pub(crate) static ref TOKEN: Mutex<Option<String>> = Mutex::new(None);
     pub(crate) static ref RESUME_GATEWAY_URL: Mutex<Option<String>> = Mutex::new(None);
-
-    static ref HELP_EMBED: tokio::sync::Mutex<Embed> = tokio::sync::Mutex::new(Embed::default());
 }
 
 /// The main client struct for interacting with the Discord API.
+///
+/// # Global settings
+///
+/// Commands, handlers and middleware belong to the client, but some settings are
+/// stored for the whole process and are shared by every client in it, the last
+/// call wins: the token and prefix passed to [`Client::new`], [`Client::prefix_resolver`],
+/// [`Client::rerun_on_edit`], [`Client::load_translations`], [`Client::set_component_secret`],
+/// [`Client::auto_defer`], [`Client::handler_timeout`], [`Client::cooldown_message`]
+/// and [`Client::on_error`]. Middleware replaces that of previously started clients
+/// when the client starts.
 pub struct Client {
     intents: u32,
-    ws: ws::WsManager,
     token: String,
-    commands: HashMap<String, Command>,
+    commands: CommandRouter,
     slash_commands: HashMap<String, SlashCommand>,
     event_handlers: HashMap<Event, EventHandler>,
-    component_handlers: HashMap<String, ComponentHandler>,
-    prefix: String,
+    component_handlers: ComponentRouter,
+    middleware: Vec<Middleware>,
 }
 
 impl Client {
@@ -46,28 +53,26 @@ impl Client {
     ///
     /// * `token` - The bot token.
     /// * `intents` - The gateway intents.
-    /// * `prefix` - The default prefix for message commands.
+    /// * `prefix` - The default prefix for message commands, see [`Client::prefix_resolver`].
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let client = Client::new("TOKEN", GatewayIntent::NON_PRIVILEGED, "!").await;
     /// ```
     pub async fn new(token: &str, intents: impl Into<u32>, prefix: &str) -> Self {
         *TOKEN.lock().unwrap() = Some(token.to_owned());
+        internals::set_default_prefix(prefix);
 
         Self {
             intents: intents.into(),
             token: token.to_owned(),
-            ws: ws::WsManager::new(token)
-                .await
-                .expect("Failed to initialize websockets"),
-            prefix: prefix.to_owned(),
 
-            commands: HashMap::new(),
+            commands: CommandRouter::new(),
             slash_commands: HashMap::new(),
             event_handlers: HashMap::new(),
-            component_handlers: HashMap::new(),
+            component_handlers: ComponentRouter::default(),
+            middleware: vec![],
         }
     }
 
@@ -75,29 +80,66 @@ impl Client {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// client.login().await;
     /// ```
     pub async fn login(mut self) {
-        self.default_help().await;
-        self.ws
-            .start(
-                self.intents,
-                ws::Handlers {
-                    event_handlers: self.event_handlers.into(),
-                    commands: self.commands.into(),
-                    slash_commands: self.slash_commands.into(),
-                    component_handlers: self.component_handlers.into(),
-                },
-            )
-            .await;
+        self.default_help();
+
+        let intents = self.intents;
+        let mut ws = ws::WsManager::new(&self.token)
+            .await
+            .expect("Failed to initialize websockets");
+
+        ws.start(intents, self.into_handlers()).await;
     }
 
-    /// Returns the bot token.
+    /// Receives interactions through an HTTP endpoint instead of the gateway.
+    ///
+    /// Set the application's "Interactions Endpoint URL" in the developer portal
+    /// to point at this server. Requests are verified with the application's
+    /// public key, slash commands, components and autocomplete are handled the
+    /// same way as with [`Client::login`], message commands and gateway events
+    /// are not available in this mode.
+    ///
+    /// The first response to an interaction is sent as the HTTP response, interactions
+    /// that aren't answered within 2.5 seconds are deferred.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - The address to listen on.
+    /// * `public_key` - The application's public key (hex encoded), found in the developer portal.
     ///
     /// # Examples
     ///
+    /// ```ignore
+    /// client.serve_interactions(([0, 0, 0, 0], 8080), "PUBLIC_KEY").await;
     /// ```
+    #[cfg(feature = "http-interactions")]
+    pub async fn serve_interactions(self, addr: impl Into<std::net::SocketAddr>, public_key: &str) {
+        let public_key = crate::http::parse_public_key(public_key).expect("Invalid public key");
+
+        if let Err(e) = crate::http::serve(addr.into(), public_key, self.into_handlers()).await {
+            error!("Interactions server stopped: {e}");
+        }
+    }
+
+    fn into_handlers(self) -> ws::Handlers {
+        internals::set_middleware(self.middleware);
+
+        ws::Handlers {
+            event_handlers: self.event_handlers.into(),
+            commands: self.commands.into(),
+            slash_commands: self.slash_commands.into(),
+            component_handlers: self.component_handlers.into(),
+        }
+    }
+
+    /// Returns the bot token.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
     /// let token = client.token();
     /// ```
     pub fn token(&self) -> &str {
@@ -112,7 +154,7 @@ impl Client {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// client.register_events(vec![events::ready(), events::message_create()]);
     /// ```
     pub fn register_events(&mut self, events: Vec<EventHandler>) {
@@ -127,23 +169,29 @@ impl Client {
 
     /// Registers callbacks for message components such as message buttons.
     ///
+    /// Handlers with exact ids take priority over patterns, patterns are tried
+    /// in the order they are registered and the fallback handler (if any)
+    /// runs when nothing else matches.
+    ///
     /// # Arguments
     ///
     /// * `commands` - A vector of component handlers.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// client.register_component_callbacks(vec![components::btn1()]);
     /// ```
     pub fn register_component_callbacks(&mut self, commands: Vec<ComponentHandler>) {
-        self.component_handlers
-            .extend(commands.into_iter().map(|d| (d.id.clone(), d)));
+        commands
+            .into_iter()
+            .for_each(|handler| self.component_handlers.insert(handler));
     }
 
     /// Registers message commands.
     /// Message commands are commands that are triggered by a message in a channel.
-    /// By default they use the prefix provided in the client constructor.
+    /// By default they use the prefix provided in the client constructor,
+    /// or the prefixes returned by the [prefix resolver](Client::prefix_resolver).
     ///
     /// # Arguments
     ///
@@ -151,7 +199,7 @@ impl Client {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// client.register_commands(vec![commands::echo()]);
     /// ```
     pub fn register_commands(&mut self, commands: Vec<Command>) {
@@ -159,18 +207,204 @@ impl Client {
             log::error!("MESSAGE_CONTENT intent is required for message commands to work");
         }
 
-        commands.into_iter().for_each(|mut command| {
-            // if a custom prefix is not applied, add the default prefix
-            if !command.custom_prefix {
-                command.name = format!(
-                    "{default_prefix}{name}",
-                    default_prefix = self.prefix,
-                    name = command.name
-                );
-            }
+        commands
+            .into_iter()
+            .for_each(|command| self.commands.insert(command));
+    }
 
-            self.commands.insert(command.name.clone(), command.clone());
-        });
+    /// Sets a function that returns the prefixes message commands can be used with.
+    ///
+    /// The resolver runs for every message, so prefixes can differ per guild or channel.
+    /// It replaces the prefix passed to [`Client::new`], commands with their own
+    /// prefix are not affected. If it returns no prefixes only mentions work as a prefix.
+    ///
+    /// This is a [global setting](Client#global-settings).
+    ///
+    /// # Arguments
+    ///
+    /// * `resolver` - An async function returning the prefixes for a message.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.prefix_resolver(|msg: Message| async move {
+    ///     match msg.guild_id.as_deref() {
+    ///         Some(guild_id) => vec![database::prefix(guild_id).await],
+    ///         None => vec!["!".to_string()],
+    ///     }
+    /// });
+    /// ```
+    pub fn prefix_resolver<F, Fut, I>(&mut self, resolver: F)
+    where
+        F: Fn(Message) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = I> + Send + 'static,
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        internals::set_prefix_resolver(Some(std::sync::Arc::new(move |message| {
+            let prefixes = resolver(message);
+            Box::pin(async move { prefixes.await.into_iter().map(Into::into).collect() })
+        })));
+    }
+
+    /// Sets whether message command names and aliases are matched case-insensitively.
+    ///
+    /// Disabled by default, the prefix is matched case-insensitively as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether to ignore case.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.case_insensitive_commands(true);
+    /// ```
+    pub fn case_insensitive_commands(&mut self, enabled: bool) {
+        self.commands.case_insensitive = enabled;
+    }
+
+    /// Sets whether mentioning the bot works as a prefix for message commands.
+    ///
+    /// Enabled by default, `@Bot ping` then runs the `ping` command.
+    ///
+    /// # Arguments
+    ///
+    /// * `enabled` - Whether a mention works as a prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.mention_prefix(false);
+    /// ```
+    pub fn mention_prefix(&mut self, enabled: bool) {
+        self.commands.mention_prefix = enabled;
+    }
+
+    /// Replies with the closest commands when a message uses a prefix with an unknown command.
+    ///
+    /// Disabled by default, e.g. `!pnig` gets "Unknown command, did you mean `!ping`?".
+    ///
+    /// # Arguments
+    ///
+    /// * `cooldown` - Limits how often suggestions are sent, so they can't be spammed.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.suggest_commands(Cooldown::parse("10s", CooldownBucket::User, 1));
+    /// ```
+    pub fn suggest_commands(&mut self, cooldown: Cooldown) {
+        self.commands.suggestions = Some(cooldown);
+    }
+
+    /// Runs message commands again when their message is edited within `window`.
+    ///
+    /// Disabled by default. The first reply of the new run edits the bot's
+    /// previous reply instead of sending a new message, the `message_update`
+    /// event handler still runs for the edit.
+    ///
+    /// This is a [global setting](Client#global-settings).
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - How long after being sent an edit runs the command, `None` disables it.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.rerun_on_edit(Some(std::time::Duration::from_secs(60)));
+    /// ```
+    pub fn rerun_on_edit(&mut self, window: Option<std::time::Duration>) {
+        internals::set_rerun_window(window);
+    }
+
+    /// Loads translations from a directory of `<locale>.json` files.
+    ///
+    /// Translations are used for slash command localizations and [`Interaction::t`],
+    /// make sure to call this before registering slash commands.
+    ///
+    /// [`Interaction::t`]: crate::models::interaction::Interaction::t
+    ///
+    /// This is a [global setting](Client#global-settings).
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The directory containing the translation files.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.load_translations("locales");
+    /// ```
+    pub fn load_translations(&mut self, dir: impl AsRef<std::path::Path>) {
+        if let Err(e) = utils::load_translations(dir) {
+            error!("Failed to load translations: {e}");
+        }
+    }
+
+    /// Sets the secret used to sign [`ComponentState`], required to use it.
+    ///
+    /// Every instance of the bot should use the same secret, changing it
+    /// invalidates the state of all existing components.
+    ///
+    /// [`ComponentState`]: crate::utils::ComponentState
+    ///
+    /// This is a [global setting](Client#global-settings).
+    ///
+    /// # Arguments
+    ///
+    /// * `secret` - The secret key.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.set_component_secret(std::env::var("COMPONENT_SECRET").unwrap());
+    /// ```
+    pub fn set_component_secret(&mut self, secret: impl Into<Vec<u8>>) {
+        utils::set_component_secret(secret);
+    }
+
+    /// Sets how long a slash command or component handler can run before the interaction is deferred.
+    ///
+    /// Defaults to 2 seconds, Discord discards interactions that aren't acknowledged
+    /// within 3 seconds. Replies after the defer edit the deferred response. Components
+    /// are deferred without a loading state, updates after the defer edit their message
+    /// and replies are sent as follow-ups.
+    ///
+    /// This is a [global setting](Client#global-settings).
+    ///
+    /// # Arguments
+    ///
+    /// * `after` - The threshold, `None` disables automatic deferring.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.auto_defer(Some(std::time::Duration::from_millis(1500)));
+    /// ```
+    pub fn auto_defer(&mut self, after: Option<std::time::Duration>) {
+        ws::interactions::set_auto_defer(after);
+    }
+
+    /// Sets how long a handler can run before it is cancelled and reported as timed out.
+    ///
+    /// Disabled by default, panics in handlers are always reported. Commands can
+    /// override it with the `timeout` attribute, e.g. `#[command(timeout = "5m")]`.
+    ///
+    /// This is a [global setting](Client#global-settings).
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The limit, `None` disables it.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.handler_timeout(Some(std::time::Duration::from_secs(60)));
+    /// ```
+    pub fn handler_timeout(&mut self, timeout: Option<std::time::Duration>) {
+        internals::set_handler_timeout(timeout);
     }
 
     /// Registers slash commands.
@@ -181,7 +415,7 @@ impl Client {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// client.register_slash_commands(vec![commands::avatar()]).await;
     /// ```
     pub async fn register_slash_commands(&mut self, commands: Vec<SlashCommand>) {
@@ -192,22 +426,185 @@ impl Client {
         );
     }
 
+    /// Sets the message sent when a command is used while on cooldown.
+    ///
+    /// Defaults to "This command is on cooldown, try again in 5s".
+    ///
+    /// This is a [global setting](Client#global-settings).
+    ///
+    /// # Arguments
+    ///
+    /// * `message` - Builds the message from the time remaining.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.cooldown_message(|remaining| {
+    ///     format!("Slow down! Try again in {}", format_duration(remaining))
+    /// });
+    /// ```
+    pub fn cooldown_message(
+        &mut self,
+        message: impl Fn(std::time::Duration) -> String + Send + Sync + 'static,
+    ) {
+        internals::set_cooldown_message(Some(std::sync::Arc::new(message)));
+    }
+
+    /// Adds middleware that runs around every command, slash command, component and event handler.
+    ///
+    /// Middleware runs in the order it was added, each one decides whether and
+    /// when to continue with `next.run()`, which returns the handler's result.
+    ///
+    /// # Arguments
+    ///
+    /// * `middleware` - An async function taking the invocation and the rest of the chain.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.around(|invocation, next| async move {
+    ///     let start = std::time::Instant::now();
+    ///     let result = next.run().await;
+    ///     log::info!("{} took {:?}", invocation.name(), start.elapsed());
+    ///
+    ///     result
+    /// });
+    /// ```
+    pub fn around<F, Fut>(&mut self, middleware: F)
+    where
+        F: Fn(Invocation, Next) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = crate::DescordResult> + Send + 'static,
+    {
+        self.middleware
+            .push(std::sync::Arc::new(move |invocation, next| {
+                Box::pin(middleware(invocation, next))
+            }));
+    }
+
+    /// Adds a hook that runs before every handler, returning an error skips the handler.
+    ///
+    /// The error is reported like errors returned by the handler.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - An async function taking the invocation.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.before(|invocation| async move {
+    ///     if invocation.user().is_some_and(|user| BANNED.contains(&user.id)) {
+    ///         return Err("You are banned from using this bot".into());
+    ///     }
+    ///
+    ///     Ok(())
+    /// });
+    /// ```
+    pub fn before<F, Fut>(&mut self, hook: F)
+    where
+        F: Fn(Invocation) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = crate::DescordResult> + Send + 'static,
+    {
+        let hook = std::sync::Arc::new(hook);
+        self.around(move |invocation, next| {
+            let hook = hook.clone();
+            async move {
+                hook(invocation).await?;
+                next.run().await
+            }
+        });
+    }
+
+    /// Adds a hook that runs after every handler with its result.
+    ///
+    /// The hook's return value replaces the handler's result.
+    ///
+    /// # Arguments
+    ///
+    /// * `hook` - An async function taking the invocation and the handler's result.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.after(|invocation, result| async move {
+    ///     if let Err(e) = &result {
+    ///         log::warn!("{} failed: {e}", invocation.name());
+    ///     }
+    ///
+    ///     result
+    /// });
+    /// ```
+    pub fn after<F, Fut>(&mut self, hook: F)
+    where
+        F: Fn(Invocation, crate::DescordResult) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = crate::DescordResult> + Send + 'static,
+    {
+        let hook = std::sync::Arc::new(hook);
+        self.around(move |invocation, next| {
+            let hook = hook.clone();
+            async move {
+                let result = next.run().await;
+                hook(invocation, result).await
+            }
+        });
+    }
+
+    /// Sets the function that handles errors returned by handlers.
+    ///
+    /// By default errors caused by the user (invalid arguments, failed checks and cooldowns)
+    /// get a reply, ephemeral for slash commands and components, and all other errors are logged.
+    /// Panics, timeouts and errors in slash commands and components also get a generic reply.
+    /// [`ErrorContext::report`] does the default, a handler that does nothing suppresses errors.
+    ///
+    /// This is a [global setting](Client#global-settings).
+    ///
+    /// # Arguments
+    ///
+    /// * `handler` - An async function taking the error along with what was being handled.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.on_error(|ctx| async move {
+    ///     match ctx.descord_error() {
+    ///         Some(DescordError::OnCooldown { .. }) => {}
+    ///         Some(DescordError::CheckFailed(reason)) => {
+    ///             log::info!("{} was denied in {:?}: {reason}", ctx.name(), ctx.guild_id());
+    ///             ctx.report().await
+    ///         }
+    ///         _ => ctx.report().await,
+    ///     }
+    /// });
+    /// ```
+    pub fn on_error<F, Fut>(&mut self, handler: F)
+    where
+        F: Fn(ErrorContext) -> Fut + Send + Sync + 'static,
+        Fut: std::future::Future<Output = ()> + Send + 'static,
+    {
+        internals::set_error_handler(Some(std::sync::Arc::new(move |ctx| Box::pin(handler(ctx)))));
+    }
+
     /// Returns info about all registered message commands.
     ///
     /// Might be useful for creating a help command.
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let commands = client.get_commands();
     /// ```
     pub fn get_commands(&self) -> Vec<CommandInfo> {
         self.commands
-            .iter()
-            .map(|(_, value)| CommandInfo {
+            .commands()
+            .map(|value| CommandInfo {
                 name: value.name.clone(),
+                prefix: value.prefix.clone(),
+                aliases: value.aliases.clone(),
+                category: value.category.clone(),
                 description: value.description.clone(),
+                usage: value.usage(),
                 params: value.fn_sig.clone(),
+                permissions: value.permissions.clone(),
             })
             .collect()
     }
@@ -218,11 +615,11 @@ impl Client {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let slash_commands = client.get_slash_commands();
     /// ```
     pub fn get_slash_commands(&self) -> Vec<SlashCommandInfo> {
-        if self.commands.is_empty() {
+        if self.commands.commands().next().is_none() {
             log::warn!("No slash commands are registered make sure to call `enable_default_help` or `get_slash_commands` after registering them.");
         }
 
@@ -231,68 +628,27 @@ impl Client {
             .map(|value| SlashCommandInfo {
                 name: value.name.clone(),
                 description: value.description.clone(),
-                params: value
-                    .fn_param_names
-                    .iter()
-                    .cloned()
-                    .zip(value.fn_sig.iter().cloned())
+                params: (0..value.fn_param_names.len())
+                    .map(|idx| (value.option_name(idx).to_string(), value.fn_sig[idx]))
                     .collect(),
+                permissions: value.permissions.clone(),
             })
             .collect()
     }
 
     /// Adds a default help command that lists all registered commands.
-    async fn default_help(&mut self) {
-        let help_cmd = format!("{}help", self.prefix);
-        if self.commands.iter().any(|(name, _)| name == &help_cmd) {
+    ///
+    /// `help <command>` shows how to use a command, commands the user lacks the
+    /// permissions for are hidden and long lists are split into pages.
+    fn default_help(&mut self) {
+        if self.commands.get("help").is_some() {
             return;
         }
 
-        let mut commands_field_text = format!("`{}help` - Sends this help message", self.prefix);
-        let mut slash_commands_field_text = String::new();
-
-        self.get_commands().into_iter().for_each(|command| {
-            commands_field_text += &format!("\n`{}` - {}", command.name, command.description);
-        });
-
-        self.get_slash_commands().into_iter().for_each(|command| {
-            slash_commands_field_text +=
-                &format!("`/{}` - {}\n", command.name, command.description);
-        });
-
-        let help_embed = crate::prelude::EmbedBuilder::new()
-            .color(crate::color::Color::Green)
-            .title("Help has arrived!")
-            .field("Message Commands", &commands_field_text, false)
-            .field("Slash Commands", &slash_commands_field_text, false)
-            .build();
-
-        *HELP_EMBED.lock().await = help_embed;
-
-        fn f(
-            msg: Message,
-            _: Vec<internals::Value>,
-        ) -> std::pin::Pin<
-            Box<dyn std::future::Future<Output = crate::DescordResult> + Send + 'static>,
-        > {
-            Box::pin(async move {
-                msg.reply(HELP_EMBED.lock().await.clone()).await;
-                Ok(())
-            })
-        }
+        self.commands.insert(super::help::help_command());
+        self.component_handlers.insert(super::help::page_buttons());
 
-        self.commands.insert(
-            format!("{}help", self.prefix),
-            Command {
-                name: "help".to_string(),
-                custom_prefix: false,
-                fn_sig: vec![],
-                handler_fn: f,
-                optional_params: vec![],
-                permissions: vec![],
-                description: "Sends this help message".to_string(),
-            },
-        );
+        super::help::set_help_commands(self.get_commands(), self.get_slash_commands());
     }
 }
 
@@ -300,8 +656,16 @@ impl Client {
 #[derive(Debug, Clone)]
 pub struct CommandInfo {
     pub name: String,
+    /// The command's own prefix, `None` if it uses the client's prefixes.
+    pub prefix: Option<String>,
+    pub aliases: Vec<String>,
+    pub category: Option<String>,
     pub description: String,
+    /// How the command is used, see [`Command::usage`].
+    pub usage: String,
     pub params: Vec<ParamType>,
+    /// Permissions the user needs to run the command.
+    pub permissions: Vec<String>,
 }
 
 /// Information about a registered slash command.
@@ -310,4 +674,5 @@ pub struct SlashCommandInfo {
     pub name: String,
     pub description: String,
     pub params: Vec<(String, ParamType)>,
+    pub permissions: Vec<String>,
 }
@@ -0,0 +1,459 @@
+use std::collections::BTreeMap;
+use std::sync::RwLock;
+
+use nanoserde::{DeBin, SerBin};
+
+use super::{CommandInfo, SlashCommandInfo};
+use crate::internals::*;
+use crate::prelude::*;
+use crate::utils::ComponentState;
+
+/// The most commands listed on one page of the help message.
+const PAGE_SIZE: usize = 10;
+
+/// The most characters in an embed field's value.
+const FIELD_LIMIT: usize = 1024;
+
+/// The most characters of fields on one page, embeds are limited to 6000 characters in total.
+const PAGE_LIMIT: usize = 4000;
+
+/// The custom id of the help message's page buttons.
+const PAGE_BUTTON_ID: &str = "descord:help";
+
+lazy_static::lazy_static! {
+    static ref HELP_COMMANDS: RwLock<Vec<CommandInfo>> = RwLock::new(vec![]);
+    static ref HELP_SLASH_COMMANDS: RwLock<Vec<SlashCommandInfo>> = RwLock::new(vec![]);
+}
+
+/// Sets the commands listed by the help command.
+pub(super) fn set_help_commands(commands: Vec<CommandInfo>, slash_commands: Vec<SlashCommandInfo>) {
+    *HELP_COMMANDS.write().unwrap() = commands;
+    *HELP_SLASH_COMMANDS.write().unwrap() = slash_commands;
+}
+
+/// The page a page button leads to.
+#[derive(SerBin, DeBin)]
+struct HelpPage {
+    page: u32,
+    /// Only the user who asked for help can change pages.
+    user_id: String,
+    prefix: String,
+}
+
+/// Returns the `help [command...]` command.
+pub(super) fn help_command() -> Command {
+    fn f(
+        msg: Message,
+        args: Vec<Value>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = crate::DescordResult> + Send + 'static>>
+    {
+        Box::pin(async move {
+            // prefixes can differ per guild
+            let prefix = resolve_prefix(&msg).await;
+            let granted = author_permissions(&msg).await.ok().flatten();
+
+            let query = match args.into_iter().next() {
+                Some(Value::Args(args)) => args.join(" "),
+                _ => String::new(),
+            };
+
+            if !query.is_empty() {
+                match command_details(&query, &prefix, granted) {
+                    Some(embed) => msg.reply(embed).await,
+                    None => {
+                        msg.reply(format!("There's no command called `{query}`"))
+                            .await
+                    }
+                };
+
+                return Ok(());
+            }
+
+            let user_id = msg.author.as_ref().map(|author| author.id.clone());
+            msg.reply(help_page(0, user_id.unwrap_or_default(), prefix, granted))
+                .await;
+
+            Ok(())
+        })
+    }
+
+    Command {
+        name: "help".to_string(),
+        aliases: vec![],
+        prefix: None,
+        category: None,
+        fn_sig: vec![ParamType::Args],
+        fn_param_names: vec!["command".to_string()],
+        fn_param_kinds: vec![ParamKind::Positional],
+        fn_param_type_names: vec!["text"],
+        fn_param_constraints: vec![OptionConstraints::default()],
+        parsers: vec![None],
+        handler_fn: f,
+        optional_params: vec![true],
+        permissions: vec![],
+        description: "Lists the commands, or shows how to use one".to_string(),
+        cooldown: None,
+        timeout: None,
+        checks: Checks::default(),
+    }
+}
+
+/// Returns the handler for the help message's page buttons.
+pub(super) fn page_buttons() -> ComponentHandler {
+    fn f(
+        interaction: Interaction,
+        _: Vec<Value>,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = crate::DescordResult> + Send + 'static>>
+    {
+        Box::pin(async move {
+            let HelpPage {
+                page,
+                user_id,
+                prefix,
+            } = interaction.state()?;
+
+            let user = interaction
+                .user
+                .as_ref()
+                .or_else(|| interaction.member.as_ref()?.user.as_ref());
+
+            if user.map(|user| user.id.as_str()) != Some(user_id.as_str()) {
+                interaction
+                    .reply("Only the user who asked for help can change pages", true)
+                    .await?;
+
+                return Ok(());
+            }
+
+            // sent with every interaction from a guild
+            let granted = interaction
+                .member
+                .as_ref()
+                .and_then(|member| member.permissions.as_ref()?.parse().ok());
+
+            interaction
+                .update_message(help_page(page, user_id, prefix, granted))
+                .await?;
+
+            Ok(())
+        })
+    }
+
+    ComponentHandler {
+        id: PAGE_BUTTON_ID.to_string(),
+        fallback: false,
+        fn_sig: vec![],
+        fn_param_names: vec![],
+        handler_fn: f,
+        checks: Checks::default(),
+    }
+}
+
+/// Returns `true` if a user with the `granted` permissions can run a command requiring `permissions`.
+///
+/// `granted` is `None` outside of guilds, where commands requiring permissions fail.
+fn is_visible(permissions: &[String], granted: Option<u64>) -> bool {
+    if permissions.is_empty() {
+        return true;
+    }
+
+    granted.is_some_and(|granted| has_permissions(granted, parse_permissions(permissions)))
+}
+
+/// Builds one page of the command list along with the page buttons.
+fn help_page(
+    page: u32,
+    user_id: String,
+    prefix: String,
+    granted: Option<u64>,
+) -> CreateMessageData {
+    let commands = HELP_COMMANDS.read().unwrap();
+    let has_categories = commands.iter().any(|command| command.category.is_some());
+
+    // uncategorized commands come last
+    let mut categories: BTreeMap<(bool, &str), Vec<&CommandInfo>> = BTreeMap::new();
+    for command in commands
+        .iter()
+        .filter(|command| is_visible(&command.permissions, granted))
+    {
+        let category = match &command.category {
+            Some(category) => (false, category.as_str()),
+            None if has_categories => (true, "Other"),
+            None => (true, "Message Commands"),
+        };
+
+        categories.entry(category).or_default().push(command);
+    }
+
+    let mut lines: Vec<(&str, String)> = vec![];
+    for ((_, category), mut commands) in categories {
+        commands.sort_by(|a, b| a.name.cmp(&b.name));
+        lines.extend(commands.into_iter().map(|command| {
+            let prefix = command.prefix.as_deref().unwrap_or(&prefix);
+            (
+                category,
+                format!("`{prefix}{}` - {}", command.name, command.description),
+            )
+        }));
+    }
+
+    let slash_commands = HELP_SLASH_COMMANDS.read().unwrap();
+    let mut slash_commands = slash_commands
+        .iter()
+        .filter(|command| is_visible(&command.permissions, granted))
+        .collect::<Vec<_>>();
+    slash_commands.sort_by(|a, b| a.name.cmp(&b.name));
+    lines.extend(slash_commands.into_iter().map(|command| {
+        (
+            "Slash Commands",
+            format!("`/{}` - {}", command.name, command.description),
+        )
+    }));
+
+    let all_pages = paginate(lines);
+    let pages = all_pages.len().max(1) as u32;
+    let page = page.min(pages - 1);
+
+    let mut embed = EmbedBuilder::new()
+        .color(Color::Green)
+        .title("Help has arrived!");
+
+    let fields = all_pages.into_iter().nth(page as usize).unwrap_or_default();
+    for (category, text) in fields {
+        embed = embed.field(category, &text, false);
+    }
+
+    let mut footer = format!("Use {prefix}help <command> to learn more about a command");
+    if pages > 1 {
+        footer = format!("Page {}/{pages} • {footer}", page + 1);
+    }
+
+    let mut response: CreateMessageData = embed.footer(&footer, None, None).build().into();
+    if pages > 1 {
+        let button = |label: &str, page: u32, disabled: bool| {
+            let custom_id = HelpPage {
+                page,
+                user_id: user_id.clone(),
+                prefix: prefix.clone(),
+            }
+            .custom_id(PAGE_BUTTON_ID)?;
+
+            Ok::<_, DescordError>(
+                ComponentBuilder::button(ButtonObject {
+                    style: ButtonStyle::Secondary as _,
+                    label: Some(label.to_string()),
+                    custom_id: Some(custom_id),
+                    disabled,
+                    ..Default::default()
+                })
+                .unwrap(),
+            )
+        };
+
+        let buttons = [
+            button("Previous", page.saturating_sub(1), page == 0),
+            button("Next", page + 1, page + 1 == pages),
+        ]
+        .into_iter()
+        .collect::<Result<Vec<_>, _>>();
+
+        match buttons {
+            Ok(buttons) => response.components = CreateMessageData::from(vec![buttons]).components,
+            Err(e) => log::warn!("Can't add page buttons to the help message: {e}"),
+        }
+    }
+
+    response
+}
+
+/// Splits the lines of the command list into pages of fields, consecutive lines of a
+/// category share a field.
+///
+/// Pages hold up to [`PAGE_SIZE`] lines, fields are kept under [`FIELD_LIMIT`]
+/// characters and pages under [`PAGE_LIMIT`], lines that are too long are truncated.
+fn paginate(lines: Vec<(&str, String)>) -> Vec<Vec<(&str, String)>> {
+    let mut pages: Vec<Vec<(&str, String)>> = vec![];
+    let mut page_lines = 0;
+    let mut page_len = 0;
+
+    for (category, line) in lines {
+        let line = truncate(&line, FIELD_LIMIT);
+        let line_len = line.chars().count();
+
+        let continues_field =
+            pages
+                .last()
+                .and_then(|page| page.last())
+                .is_some_and(|(last, text)| {
+                    *last == category && text.chars().count() + 1 + line_len <= FIELD_LIMIT
+                });
+
+        let added_len = if continues_field {
+            line_len + 1
+        } else {
+            category.len() + line_len
+        };
+
+        if pages.is_empty() || page_lines == PAGE_SIZE || page_len + added_len > PAGE_LIMIT {
+            pages.push(vec![(category, line)]);
+            page_lines = 1;
+            page_len = category.len() + line_len;
+            continue;
+        }
+
+        let page = pages.last_mut().unwrap();
+        if continues_field {
+            let text = &mut page.last_mut().unwrap().1;
+            text.push('\n');
+            text.push_str(&line);
+        } else {
+            page.push((category, line));
+        }
+
+        page_lines += 1;
+        page_len += added_len;
+    }
+
+    pages
+}
+
+/// Shortens `text` to at most `max` characters, marking it with an ellipsis if it was cut.
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+
+    let mut text: String = text.chars().take(max - 1).collect();
+    text.push('…');
+    text
+}
+
+/// Builds the detail page for a message or slash command, looked up by name or alias.
+fn command_details(query: &str, prefix: &str, granted: Option<u64>) -> Option<Embed> {
+    let commands = HELP_COMMANDS.read().unwrap();
+    let query = query.to_lowercase();
+
+    let command = commands.iter().find(|command| {
+        is_visible(&command.permissions, granted)
+            && std::iter::once(&command.name)
+                .chain(&command.aliases)
+                .any(|name| name.to_lowercase() == query)
+    });
+
+    if let Some(command) = command {
+        let prefix = command.prefix.as_deref().unwrap_or(prefix);
+        let mut embed = EmbedBuilder::new()
+            .color(Color::Green)
+            .title(&format!("{prefix}{}", command.name))
+            .description(&command.description)
+            .field("Usage", &format!("`{prefix}{}`", command.usage), false);
+
+        if !command.aliases.is_empty() {
+            let aliases = command
+                .aliases
+                .iter()
+                .map(|alias| format!("`{prefix}{alias}`"))
+                .collect::<Vec<_>>();
+
+            embed = embed.field("Aliases", &aliases.join(", "), false);
+        }
+
+        if let Some(category) = &command.category {
+            embed = embed.field("Category", category, true);
+        }
+
+        if !command.permissions.is_empty() {
+            embed = embed.field("Permissions", &command.permissions.join(", "), true);
+        }
+
+        return Some(embed.build());
+    }
+
+    let slash_commands = HELP_SLASH_COMMANDS.read().unwrap();
+    let query = query.strip_prefix('/').unwrap_or(&query);
+    let command = slash_commands.iter().find(|command| {
+        is_visible(&command.permissions, granted) && command.name.to_lowercase() == query
+    })?;
+
+    let mut embed = EmbedBuilder::new()
+        .color(Color::Green)
+        .title(&format!("/{}", command.name))
+        .description(&command.description);
+
+    if !command.params.is_empty() {
+        let options = command
+            .params
+            .iter()
+            .map(|(name, param_type)| {
+                format!("`{name}: {}`", format!("{param_type:?}").to_lowercase())
+            })
+            .collect::<Vec<_>>();
+
+        embed = embed.field(
+            "Options",
+            &truncate(&options.join("\n"), FIELD_LIMIT),
+            false,
+        );
+    }
+
+    if !command.permissions.is_empty() {
+        embed = embed.field("Permissions", &command.permissions.join(", "), true);
+    }
+
+    Some(embed.build())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(category: &str, count: usize, len: usize) -> Vec<(&str, String)> {
+        (0..count).map(|_| (category, "x".repeat(len))).collect()
+    }
+
+    #[test]
+    fn groups_lines_by_category() {
+        let mut all = lines("Fun", 3, 10);
+        all.extend(lines("Admin", 2, 10));
+
+        let pages = paginate(all);
+        assert_eq!(pages.len(), 1);
+        assert_eq!(pages[0].len(), 2);
+        assert_eq!(pages[0][0].1.lines().count(), 3);
+        assert_eq!(pages[0][1].1.lines().count(), 2);
+    }
+
+    #[test]
+    fn limits_lines_per_page() {
+        let pages = paginate(lines("Fun", PAGE_SIZE * 2 + 1, 10));
+        assert_eq!(pages.len(), 3);
+        assert_eq!(pages[2][0].1.lines().count(), 1);
+    }
+
+    #[test]
+    fn keeps_fields_and_pages_under_the_limits() {
+        let pages = paginate(lines("Fun", 30, 300));
+
+        for page in &pages {
+            let page_len: usize = page.iter().map(|(_, text)| text.len()).sum();
+            assert!(page_len <= PAGE_LIMIT);
+            assert!(page.iter().all(|(_, text)| text.len() <= FIELD_LIMIT));
+        }
+
+        let lines: usize = pages
+            .iter()
+            .flatten()
+            .map(|(_, text)| text.lines().count())
+            .sum();
+        assert_eq!(lines, 30);
+    }
+
+    #[test]
+    fn truncates_long_lines() {
+        let pages = paginate(lines("Fun", 1, 2000));
+        assert_eq!(pages[0][0].1.chars().count(), FIELD_LIMIT);
+        assert!(pages[0][0].1.ends_with('…'));
+
+        assert_eq!(truncate("short", 10), "short");
+    }
+}
@@ -1,3 +1,4 @@
 mod client;
+mod help;
 
 pub use client::*;
@@ -5,6 +5,14 @@ pub const ROLE_CACHE_SIZE: usize = 100_000;
 pub const GUILD_CACHE_SIZE: usize = 30_000;
 pub const RATE_LIMITS_CACHE_SIZE: usize = 50_000;
 pub const CHANNEL_CACHE_SIZE: usize = 50_000;
+pub const COMPONENT_STATE_CACHE_SIZE: usize = 10_000;
+pub const INTERACTION_RESPONSES_CACHE_SIZE: usize = 10_000;
+pub const COMMAND_REPLIES_CACHE_SIZE: usize = 10_000;
+
+/// Discord epoch (2015-01-01) in milliseconds, used in snowflakes.
+pub const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+/// How long an interaction token can be used for.
+pub const INTERACTION_TOKEN_LIFETIME: std::time::Duration = std::time::Duration::from_secs(15 * 60);
 pub const DISCORD_CDN: &str = "https://cdn.discordapp.com";
 
 #[derive(Debug, Clone, Copy)]
@@ -137,12 +145,42 @@ pub enum InteractionType {
     ModalSubmit,
 }
 
+/// Where an interaction can be used, or was triggered from.
 #[repr(u32)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InteractionContextType {
+    /// Inside of a guild
     Guild,
+
+    /// DMs with the bot user
     BotDm,
-    PrivateChanne,
+
+    /// Group DMs and DMs other than the bot user's
+    PrivateChannel,
+}
+
+impl TryFrom<u32> for InteractionContextType {
+    type Error = u32;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Guild,
+            1 => Self::BotDm,
+            2 => Self::PrivateChannel,
+            _ => return Err(value),
+        })
+    }
+}
+
+/// Where an app can be installed.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntegrationType {
+    /// Installable to servers
+    GuildInstall,
+
+    /// Installable to users
+    UserInstall,
 }
 
 #[repr(u32)]
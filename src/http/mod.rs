@@ -0,0 +1,284 @@
+//! Receiving interactions through an HTTP endpoint instead of the gateway.
+
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use ed25519_dalek::{Signature, Verifier, VerifyingKey};
+use hyper::header::{HeaderMap, CONTENT_TYPE};
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Method, Request, Response, Server, StatusCode};
+use log::*;
+use tokio::sync::oneshot;
+
+use crate::consts::InteractionType;
+use crate::internals::{guard, handle_error, HandlerValue};
+use crate::ws::interactions::{dispatch_interaction, parse_interaction};
+use crate::ws::Handlers;
+use crate::Event;
+
+/// How long to wait for the handlers before deferring the interaction,
+/// discord expects an answer within 3 seconds.
+const DISPATCH_TIMEOUT: Duration = Duration::from_millis(2500);
+
+/// How far the signature timestamp can be from the current time, in seconds.
+const MAX_TIMESTAMP_SKEW: u64 = 5;
+
+lazy_static::lazy_static! {
+    /// Interactions received over HTTP that weren't responded to yet, by interaction id.
+    static ref PENDING_RESPONSES: Mutex<HashMap<String, oneshot::Sender<String>>> =
+        Mutex::new(HashMap::new());
+}
+
+/// Takes the channel the initial response to an interaction is sent through as the
+/// HTTP response, `None` if the interaction came through the gateway or was already answered.
+pub(crate) fn take_response_channel(interaction_id: &str) -> Option<oneshot::Sender<String>> {
+    PENDING_RESPONSES.lock().unwrap().remove(interaction_id)
+}
+
+/// Parses a hex encoded Ed25519 public key.
+pub(crate) fn parse_public_key(
+    public_key: &str,
+) -> Result<VerifyingKey, Box<dyn std::error::Error>> {
+    let bytes: [u8; 32] = hex::decode(public_key.trim())?
+        .try_into()
+        .map_err(|_| "Public key must be 32 bytes long")?;
+
+    Ok(VerifyingKey::from_bytes(&bytes)?)
+}
+
+/// Starts the interactions server, runs until the server fails.
+pub(crate) async fn serve(
+    addr: SocketAddr,
+    public_key: VerifyingKey,
+    handlers: Handlers,
+) -> hyper::Result<()> {
+    let make_service = make_service_fn(move |_| {
+        let handlers = handlers.clone();
+
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                handle_request(request, public_key, handlers.clone())
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&addr)?.serve(make_service);
+    info!("Listening for interactions on {addr}");
+
+    server.await
+}
+
+async fn handle_request(
+    request: Request<Body>,
+    public_key: VerifyingKey,
+    handlers: Handlers,
+) -> Result<Response<Body>, Infallible> {
+    if request.method() != Method::POST {
+        return Ok(status(StatusCode::METHOD_NOT_ALLOWED));
+    }
+
+    let (parts, body) = request.into_parts();
+    let Ok(body) = hyper::body::to_bytes(body).await else {
+        return Ok(status(StatusCode::BAD_REQUEST));
+    };
+
+    if !verify_signature(&public_key, &parts.headers, &body, unix_time()) {
+        warn!("Rejected an interaction request with an invalid signature");
+        return Ok(status(StatusCode::UNAUTHORIZED));
+    }
+
+    let Some(json) = std::str::from_utf8(&body)
+        .ok()
+        .and_then(|body| json::parse(body).ok())
+    else {
+        return Ok(status(StatusCode::BAD_REQUEST));
+    };
+
+    if json["type"].as_u32() == Some(InteractionType::Ping as u32) {
+        return Ok(Response::builder()
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(r#"{"type":1}"#))
+            .unwrap());
+    }
+
+    let interaction = match parse_interaction(json) {
+        Ok(interaction) => interaction,
+        Err(e) => {
+            error!("Failed to parse interaction: {e}");
+            return Ok(status(StatusCode::BAD_REQUEST));
+        }
+    };
+
+    // The initial response is sent back as the HTTP response
+    let (sender, mut receiver) = oneshot::channel();
+    PENDING_RESPONSES
+        .lock()
+        .unwrap()
+        .insert(interaction.id.clone(), sender);
+
+    let pending = interaction.clone();
+    tokio::spawn(async move {
+        dispatch_interaction(&interaction, &handlers).await;
+
+        if let Some(handler) = handlers.event_handlers.get(&Event::InteractionCreate) {
+            let data: HandlerValue = interaction.into();
+            if let Err(e) = guard(None, handler.call(data.clone())).await {
+                handle_error(handler.invocation(&data), e).await;
+            }
+        }
+    });
+
+    let response = match tokio::time::timeout(DISPATCH_TIMEOUT, &mut receiver).await {
+        Ok(response) => response,
+        Err(_) => {
+            // Too slow, defer so the interaction doesn't fail. Does nothing if a
+            // response was sent in the meantime, which is then already received.
+            let deferred = if pending.type_ == InteractionType::MessageComponent as u32 {
+                pending.defer_update().await
+            } else {
+                pending.defer().await
+            };
+
+            if let Err(e) = deferred {
+                error!("Failed to defer interaction {}: {e}", pending.id);
+            }
+
+            PENDING_RESPONSES.lock().unwrap().remove(&pending.id);
+            receiver.await
+        }
+    };
+
+    Ok(match response {
+        Ok(body) => Response::builder()
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .unwrap(),
+
+        // responses with attachments go through the callback endpoint
+        Err(_) => status(StatusCode::ACCEPTED),
+    })
+}
+
+/// Returns the current unix time in seconds.
+fn unix_time() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|time| time.as_secs())
+        .unwrap_or_default()
+}
+
+/// Checks the request signature, rejecting requests whose timestamp is too far from `now`
+/// so captured requests can't be replayed.
+fn verify_signature(public_key: &VerifyingKey, headers: &HeaderMap, body: &[u8], now: u64) -> bool {
+    let header = |name| headers.get(name).and_then(|value| value.to_str().ok());
+
+    let (Some(signature), Some(timestamp)) = (
+        header("X-Signature-Ed25519"),
+        header("X-Signature-Timestamp"),
+    ) else {
+        return false;
+    };
+
+    let fresh = timestamp
+        .parse::<u64>()
+        .is_ok_and(|timestamp| timestamp.abs_diff(now) <= MAX_TIMESTAMP_SKEW);
+
+    if !fresh {
+        return false;
+    }
+
+    let Some(signature) = hex::decode(signature)
+        .ok()
+        .and_then(|bytes| Signature::from_slice(&bytes).ok())
+    else {
+        return false;
+    };
+
+    let message = [timestamp.as_bytes(), body].concat();
+    public_key.verify(&message, &signature).is_ok()
+}
+
+fn status(code: StatusCode) -> Response<Body> {
+    Response::builder()
+        .status(code)
+        .body(Body::empty())
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use ed25519_dalek::{Signer, SigningKey};
+
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+    const BODY: &[u8] = br#"{"type":1}"#;
+
+    fn headers(signature: &[u8], timestamp: u64) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            "X-Signature-Ed25519",
+            hex::encode(signature).parse().unwrap(),
+        );
+        headers.insert(
+            "X-Signature-Timestamp",
+            timestamp.to_string().parse().unwrap(),
+        );
+        headers
+    }
+
+    fn sign(key: &SigningKey, timestamp: u64, body: &[u8]) -> Vec<u8> {
+        let message = [timestamp.to_string().as_bytes(), body].concat();
+        key.sign(&message).to_bytes().to_vec()
+    }
+
+    #[test]
+    fn accepts_signed_requests() {
+        let key = SigningKey::from_bytes(&[1; 32]);
+        let signature = sign(&key, NOW, BODY);
+
+        assert!(verify_signature(
+            &key.verifying_key(),
+            &headers(&signature, NOW),
+            BODY,
+            NOW
+        ));
+    }
+
+    #[test]
+    fn rejects_stale_timestamps() {
+        let key = SigningKey::from_bytes(&[1; 32]);
+        let timestamp = NOW - MAX_TIMESTAMP_SKEW - 1;
+        let signature = sign(&key, timestamp, BODY);
+
+        assert!(!verify_signature(
+            &key.verifying_key(),
+            &headers(&signature, timestamp),
+            BODY,
+            NOW
+        ));
+    }
+
+    #[test]
+    fn rejects_bad_signatures() {
+        let key = SigningKey::from_bytes(&[1; 32]);
+        let other = SigningKey::from_bytes(&[2; 32]);
+        let signature = sign(&other, NOW, BODY);
+
+        assert!(!verify_signature(
+            &key.verifying_key(),
+            &headers(&signature, NOW),
+            BODY,
+            NOW
+        ));
+        assert!(!verify_signature(
+            &key.verifying_key(),
+            &headers(&sign(&key, NOW, BODY), NOW),
+            br#"{"type":2}"#,
+            NOW
+        ));
+    }
+}
@@ -0,0 +1,441 @@
+use std::any::Any;
+use std::sync::Arc;
+use std::time::Duration;
+
+use super::*;
+use crate::models::emoji::Emoji;
+
+pub type ArgumentFuture<'a, T> =
+    std::pin::Pin<Box<dyn futures_util::Future<Output = Result<T, String>> + Send + 'a>>;
+
+/// Parses the argument of a command parameter, `None` if it was omitted.
+pub type ArgumentParser = for<'a> fn(Option<&'a str>, &'a Message) -> ArgumentFuture<'a, Value>;
+
+/// A type that can be parsed from a message command argument.
+///
+/// The error is shown to the user along with the command's usage line.
+/// Types implementing [`FromStr`] can use [`from_str_argument!`] instead.
+///
+/// [`FromStr`]: std::str::FromStr
+/// [`from_str_argument!`]: crate::from_str_argument
+///
+/// # Examples
+///
+/// ```ignore
+/// struct Hex(u32);
+///
+/// impl FromArgument for Hex {
+///     fn type_name() -> &'static str {
+///         "hex number"
+///     }
+///
+///     fn from_argument<'a>(arg: &'a str, _: &'a Message) -> ArgumentFuture<'a, Self> {
+///         Box::pin(async move {
+///             let digits = arg.trim_start_matches("0x");
+///             u32::from_str_radix(digits, 16)
+///                 .map(Hex)
+///                 .map_err(|_| format!("`{arg}` is not a hex number"))
+///         })
+///     }
+/// }
+///
+/// #[command]
+/// async fn color(msg: Message, color: Hex) {
+///     // ...
+/// }
+/// ```
+pub trait FromArgument: Sized + Send + Sync + 'static {
+    /// Name of the type, shown in the command's usage line.
+    fn type_name() -> &'static str;
+
+    /// The closest parameter type, used when listing commands.
+    fn param_type() -> ParamType {
+        ParamType::String
+    }
+
+    /// Parses the argument, `message` is the message that invoked the command.
+    fn from_argument<'a>(arg: &'a str, message: &'a Message) -> ArgumentFuture<'a, Self>;
+}
+
+/// Implements [`FromArgument`] for a type implementing [`FromStr`].
+///
+/// [`FromArgument`]: crate::internals::FromArgument
+/// [`FromStr`]: std::str::FromStr
+///
+/// # Examples
+///
+/// ```ignore
+/// enum Mode {
+///     Fast,
+///     Slow,
+/// }
+///
+/// impl std::str::FromStr for Mode {
+///     type Err = String;
+///
+///     fn from_str(s: &str) -> Result<Self, Self::Err> {
+///         match s {
+///             "fast" => Ok(Mode::Fast),
+///             "slow" => Ok(Mode::Slow),
+///             _ => Err("expected `fast` or `slow`".to_string()),
+///         }
+///     }
+/// }
+///
+/// descord::from_str_argument!(Mode => "fast|slow");
+/// ```
+#[macro_export]
+macro_rules! from_str_argument {
+    [ $($ty:ty => $type_name:expr),* $(,)? ] => {
+        $(
+            impl $crate::internals::FromArgument for $ty {
+                fn type_name() -> &'static str {
+                    $type_name
+                }
+
+                fn from_argument<'a>(
+                    arg: &'a str,
+                    _: &'a $crate::prelude::Message,
+                ) -> $crate::internals::ArgumentFuture<'a, Self> {
+                    Box::pin(async move {
+                        arg.parse::<$ty>().map_err(|e| e.to_string())
+                    })
+                }
+            }
+        )*
+    };
+}
+
+from_str_argument![
+    String => "text",
+    char => "character",
+];
+
+/// Implements [`FromArgument`] for numeric types, with the same error for every type.
+macro_rules! number_argument {
+    ($type_name:expr, $param_type:ident, $error:expr => $($ty:ty),*) => {
+        $(
+            impl FromArgument for $ty {
+                fn type_name() -> &'static str {
+                    $type_name
+                }
+
+                fn param_type() -> ParamType {
+                    ParamType::$param_type
+                }
+
+                fn from_argument<'a>(arg: &'a str, _: &'a Message) -> ArgumentFuture<'a, Self> {
+                    Box::pin(async move { arg.parse().map_err(|_| format!($error, arg)) })
+                }
+            }
+        )*
+    };
+}
+
+number_argument!("integer", Int, "`{}` is not an integer" => i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+number_argument!("number", Number, "`{}` is not a number" => f32, f64);
+
+impl FromArgument for bool {
+    fn type_name() -> &'static str {
+        "yes/no"
+    }
+
+    fn param_type() -> ParamType {
+        ParamType::Bool
+    }
+
+    fn from_argument<'a>(arg: &'a str, _: &'a Message) -> ArgumentFuture<'a, Self> {
+        Box::pin(async move {
+            match arg.to_lowercase().as_str() {
+                "true" | "yes" | "y" | "on" | "1" => Ok(true),
+                "false" | "no" | "n" | "off" | "0" => Ok(false),
+                _ => Err(format!("`{arg}` is not yes or no")),
+            }
+        })
+    }
+}
+
+impl FromArgument for Duration {
+    fn type_name() -> &'static str {
+        "duration"
+    }
+
+    fn from_argument<'a>(arg: &'a str, _: &'a Message) -> ArgumentFuture<'a, Self> {
+        Box::pin(async move {
+            parse_duration(arg)
+                .ok_or_else(|| format!("`{arg}` is not a duration, try something like `1h30m`"))
+        })
+    }
+}
+
+impl FromArgument for User {
+    fn type_name() -> &'static str {
+        "user"
+    }
+
+    fn param_type() -> ParamType {
+        ParamType::User
+    }
+
+    fn from_argument<'a>(arg: &'a str, _: &'a Message) -> ArgumentFuture<'a, Self> {
+        Box::pin(async move {
+            let id = parse_mention(arg, &["@!", "@"]).ok_or_else(|| not_found("user", arg))?;
+            fetch_user(id).await.map_err(|_| not_found("user", arg))
+        })
+    }
+}
+
+impl FromArgument for Member {
+    fn type_name() -> &'static str {
+        "member"
+    }
+
+    fn param_type() -> ParamType {
+        ParamType::User
+    }
+
+    fn from_argument<'a>(arg: &'a str, message: &'a Message) -> ArgumentFuture<'a, Self> {
+        Box::pin(async move {
+            let guild_id = message.guild_id.as_deref().ok_or_else(guild_only)?;
+            let id = parse_mention(arg, &["@!", "@"]).ok_or_else(|| not_found("member", arg))?;
+
+            fetch_member(guild_id, id)
+                .await
+                .map_err(|_| not_found("member", arg))
+        })
+    }
+}
+
+impl FromArgument for Role {
+    fn type_name() -> &'static str {
+        "role"
+    }
+
+    fn from_argument<'a>(arg: &'a str, message: &'a Message) -> ArgumentFuture<'a, Self> {
+        Box::pin(async move {
+            let guild_id = message.guild_id.as_deref().ok_or_else(guild_only)?;
+
+            // roles can also be referred to by name
+            let roles = fetch_roles(guild_id).await.map_err(|e| e.to_string())?;
+            let id = parse_mention(arg, &["@&"]);
+
+            roles
+                .into_iter()
+                .find(|role| Some(role.id.as_str()) == id || role.name.eq_ignore_ascii_case(arg))
+                .ok_or_else(|| not_found("role", arg))
+        })
+    }
+}
+
+impl FromArgument for Channel {
+    fn type_name() -> &'static str {
+        "channel"
+    }
+
+    fn param_type() -> ParamType {
+        ParamType::Channel
+    }
+
+    fn from_argument<'a>(arg: &'a str, _: &'a Message) -> ArgumentFuture<'a, Self> {
+        Box::pin(async move {
+            let id = parse_mention(arg, &["#"]).ok_or_else(|| not_found("channel", arg))?;
+            fetch_channel(id)
+                .await
+                .map_err(|_| not_found("channel", arg))
+        })
+    }
+}
+
+impl FromArgument for Emoji {
+    fn type_name() -> &'static str {
+        "emoji"
+    }
+
+    fn from_argument<'a>(arg: &'a str, _: &'a Message) -> ArgumentFuture<'a, Self> {
+        Box::pin(async move {
+            let custom = (arg.starts_with("<:") || arg.starts_with("<a:")) && arg.ends_with('>');
+
+            if custom {
+                let mut emoji = Emoji::parse(arg.trim_start_matches("<a"));
+                emoji.animated = Some(arg.starts_with("<a:"));
+                Ok(emoji)
+            } else if is_unicode_emoji(arg) {
+                Ok(Emoji::parse(arg))
+            } else {
+                Err(format!("`{arg}` is not an emoji"))
+            }
+        })
+    }
+}
+
+/// [`ArgumentParser`] for a required parameter of type `T`.
+pub fn parse_argument<'a, T: FromArgument>(
+    arg: Option<&'a str>,
+    message: &'a Message,
+) -> ArgumentFuture<'a, Value> {
+    Box::pin(async move {
+        match arg {
+            Some(arg) => T::from_argument(arg, message).await.map(Value::any),
+            // the parameter has a default value
+            None => Ok(Value::None),
+        }
+    })
+}
+
+/// [`ArgumentParser`] for an `Option<T>` parameter.
+pub fn parse_optional_argument<'a, T: FromArgument>(
+    arg: Option<&'a str>,
+    message: &'a Message,
+) -> ArgumentFuture<'a, Value> {
+    Box::pin(async move {
+        match arg {
+            Some(arg) => T::from_argument(arg, message)
+                .await
+                .map(|value| Value::any(Some(value))),
+            None => Ok(Value::any(None::<T>)),
+        }
+    })
+}
+
+impl Value {
+    /// Wraps a parsed argument.
+    pub fn any<T: Any + Send + Sync>(value: T) -> Self {
+        Value::Any(Arc::new(value))
+    }
+
+    /// Takes a parsed argument out of a [`Value::Any`].
+    pub fn downcast<T: Any + Send + Sync>(self) -> Option<T> {
+        match self {
+            Value::Any(value) => Arc::try_unwrap(value.downcast::<T>().ok()?).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Returns `true` if `arg` is a single unicode emoji, including sequences like flags,
+/// keycaps and emoji joined with zero width joiners.
+fn is_unicode_emoji(arg: &str) -> bool {
+    fn is_pictograph(c: char) -> bool {
+        matches!(
+            c as u32,
+            0x1F000..=0x1FAFF
+                | 0x2600..=0x27BF
+                | 0x2300..=0x23FF
+                | 0x2B00..=0x2BFF
+                | 0x2190..=0x21FF
+                | 0x25A0..=0x25FF
+                | 0x2934..=0x2935
+                | 0x3030
+                | 0x303D
+                | 0x3297
+                | 0x3299
+                | 0x00A9
+                | 0x00AE
+                | 0x203C
+                | 0x2049
+                | 0x2122
+                | 0x2139
+                | 0x24C2
+        )
+    }
+
+    // joiners, variation selectors and tags only modify a pictograph
+    fn is_modifier(c: char) -> bool {
+        matches!(c as u32, 0x200D | 0xFE0E | 0xFE0F | 0xE0020..=0xE007F)
+    }
+
+    // keycaps like 1️⃣ start with an ascii character
+    if let Some(key) = arg.strip_suffix('\u{20E3}') {
+        let key = key.trim_end_matches('\u{FE0F}');
+        return matches!(key, "#" | "*") || (key.len() == 1 && key.as_bytes()[0].is_ascii_digit());
+    }
+
+    arg.chars().next().is_some_and(is_pictograph)
+        && arg.chars().all(|c| is_pictograph(c) || is_modifier(c))
+}
+
+/// Returns the id in a mention like `<@1234>` or a plain id.
+fn parse_mention<'a>(arg: &'a str, prefixes: &[&str]) -> Option<&'a str> {
+    let id = match arg.strip_prefix('<').and_then(|arg| arg.strip_suffix('>')) {
+        Some(mention) => prefixes
+            .iter()
+            .find_map(|prefix| mention.strip_prefix(prefix))?,
+        None => arg,
+    };
+
+    (!id.is_empty() && id.bytes().all(|b| b.is_ascii_digit())).then_some(id)
+}
+
+/// Parses durations like `90`, `45s`, `10m` or `1h30m`, plain numbers are seconds.
+pub(crate) fn parse_duration(arg: &str) -> Option<Duration> {
+    if arg.is_empty() {
+        return None;
+    }
+
+    if let Ok(seconds) = arg.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let mut total: u64 = 0;
+    let mut rest = arg.to_lowercase();
+
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+        let amount = rest[..digits].parse::<u64>().ok()?;
+        let unit_len = rest[digits..]
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len() - digits);
+
+        let seconds = match &rest[digits..digits + unit_len] {
+            "s" | "sec" | "secs" => 1,
+            "m" | "min" | "mins" => 60,
+            "h" | "hr" | "hrs" => 60 * 60,
+            "d" | "day" | "days" => 24 * 60 * 60,
+            "w" | "week" | "weeks" => 7 * 24 * 60 * 60,
+            _ => return None,
+        };
+
+        total = total.checked_add(amount.checked_mul(seconds)?)?;
+        rest.drain(..digits + unit_len);
+    }
+
+    Some(Duration::from_secs(total))
+}
+
+fn not_found(type_name: &str, arg: &str) -> String {
+    format!("couldn't find a {type_name} named `{arg}`")
+}
+
+fn guild_only() -> String {
+    "this command can only be used in a server".to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_durations() {
+        assert_eq!(parse_duration("90"), Some(Duration::from_secs(90)));
+        assert_eq!(parse_duration("1h30m"), Some(Duration::from_secs(5400)));
+    }
+
+    #[test]
+    fn rejects_invalid_durations() {
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("5x"), None);
+        assert_eq!(parse_duration("18446744073709551615w"), None);
+    }
+
+    #[test]
+    fn detects_unicode_emoji() {
+        assert!(is_unicode_emoji("👍"));
+        assert!(is_unicode_emoji("👍🏽"));
+        assert!(is_unicode_emoji("🇳🇱"));
+        assert!(is_unicode_emoji("1️⃣"));
+        assert!(is_unicode_emoji("👨‍👩‍👧"));
+        assert!(!is_unicode_emoji("héllo"));
+        assert!(!is_unicode_emoji("日本"));
+        assert!(!is_unicode_emoji(""));
+    }
+}
@@ -0,0 +1,233 @@
+use std::fmt::Display;
+
+use crate::client::BOT_ID;
+use crate::consts::permissions::{self, ADMINISTRATOR};
+use crate::models::hybrid_context::HybridContext;
+
+use super::*;
+
+pub type CheckFn = fn(
+    HybridContext,
+) -> std::pin::Pin<
+    Box<dyn futures_util::Future<Output = Result<(), String>> + Send + 'static>,
+>;
+
+lazy_static::lazy_static! {
+    // Fetched the first time an `owner_only` check runs
+    static ref OWNERS: tokio::sync::Mutex<Option<Vec<String>>> = tokio::sync::Mutex::new(None);
+}
+
+/// A custom check, created with `#[descord::check]`.
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub name: String,
+    pub handler_fn: CheckFn,
+}
+
+/// Return types of `#[descord::check]` functions.
+///
+/// `false` fails with a generic message, `Err` fails with the error as the message.
+pub trait CheckResult {
+    fn into_result(self) -> Result<(), String>;
+}
+
+impl CheckResult for bool {
+    fn into_result(self) -> Result<(), String> {
+        match self {
+            true => Ok(()),
+            false => Err("You can't use this".to_string()),
+        }
+    }
+}
+
+impl<E: Display> CheckResult for Result<(), E> {
+    fn into_result(self) -> Result<(), String> {
+        self.map_err(|e| e.to_string())
+    }
+}
+
+/// Conditions that must hold before a command or component handler runs.
+///
+/// Custom checks run last, in order.
+#[derive(Debug, Clone, Default)]
+pub struct Checks {
+    pub guild_only: bool,
+    pub dm_only: bool,
+    /// Only the application owner, or members of its team.
+    pub owner_only: bool,
+    pub nsfw_only: bool,
+    /// Permissions the bot needs in the channel.
+    pub bot_permissions: Vec<String>,
+    pub custom: Vec<Check>,
+}
+
+impl Checks {
+    /// Runs the checks, returning [`DescordError::CheckFailed`] for the first one that fails.
+    pub async fn run(&self, ctx: &HybridContext) -> Result<(), DescordError> {
+        let in_guild = ctx.guild_id().is_some();
+
+        if self.guild_only && !in_guild {
+            return Err(fail("This can only be used in a server"));
+        }
+
+        if self.dm_only && in_guild {
+            return Err(fail("This can only be used in DMs"));
+        }
+
+        if self.owner_only && !is_owner(ctx).await? {
+            return Err(fail("Only the bot owner can use this"));
+        }
+
+        if self.nsfw_only && !is_nsfw_channel(ctx).await {
+            return Err(fail("This can only be used in NSFW channels"));
+        }
+
+        if !self.bot_permissions.is_empty() {
+            let required = parse_permissions(&self.bot_permissions);
+            let granted = bot_permissions(ctx).await?;
+
+            if !has_permissions(granted, required) {
+                return Err(fail(format!(
+                    "I need the {} permission(s) to do this",
+                    self.bot_permissions.join(", ")
+                )));
+            }
+        }
+
+        for check in &self.custom {
+            (check.handler_fn)(ctx.clone())
+                .await
+                .map_err(DescordError::CheckFailed)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks that the author of a message command has `permissions` in the channel.
+///
+/// Permissions only exist in guilds, so the command fails in DMs.
+pub(crate) async fn check_member_permissions(
+    message: &Message,
+    permissions: &[String],
+) -> Result<(), DescordError> {
+    if permissions.is_empty() {
+        return Ok(());
+    }
+
+    let Some(granted) = author_permissions(message).await? else {
+        return Err(fail("This can only be used in a server"));
+    };
+
+    if !has_permissions(granted, parse_permissions(permissions)) {
+        return Err(fail(
+            "You are missing the required permissions for running this command",
+        ));
+    }
+
+    Ok(())
+}
+
+/// Returns the permissions the author of a message has in the channel, `None` in DMs.
+pub(crate) async fn author_permissions(message: &Message) -> Result<Option<u64>, DescordError> {
+    let (Some(guild_id), Some(mut member)) = (message.guild_id.as_deref(), message.member.clone())
+    else {
+        return Ok(None);
+    };
+
+    // message members don't contain the user
+    member.user = message.author.clone();
+
+    member_permissions(guild_id, &message.channel_id, &member)
+        .await
+        .map(Some)
+}
+
+fn fail(reason: impl Into<String>) -> DescordError {
+    DescordError::CheckFailed(reason.into())
+}
+
+pub(crate) fn parse_permissions(names: &[String]) -> u64 {
+    names.iter().fold(0, |permissions, name| {
+        permissions | permissions::parse(name).expect("Invalid permission name")
+    })
+}
+
+pub(crate) fn has_permissions(granted: u64, required: u64) -> bool {
+    granted & ADMINISTRATOR != 0 || granted & required == required
+}
+
+async fn member_permissions(
+    guild_id: &str,
+    channel_id: &str,
+    member: &Member,
+) -> Result<u64, DescordError> {
+    // the errors aren't `Send`
+    let guild = fetch_guild(guild_id).await.map_err(|e| e.to_string());
+    let channel = fetch_channel(channel_id).await.map_err(|e| e.to_string());
+
+    match (guild, channel) {
+        (Ok(guild), Ok(channel)) => Ok(fetch_permissions(member, &guild, Some(&channel)).await),
+        (Err(e), _) | (_, Err(e)) => {
+            log::error!("Failed to fetch permissions: {e}");
+            Err(fail("Couldn't check permissions, try again later"))
+        }
+    }
+}
+
+async fn is_owner(ctx: &HybridContext) -> Result<bool, DescordError> {
+    let Some(user) = ctx.user() else {
+        return Ok(false);
+    };
+
+    let mut owners = OWNERS.lock().await;
+    if owners.is_none() {
+        *owners = Some(fetch_application_owners().await?);
+    }
+
+    Ok(owners.iter().flatten().any(|id| *id == user.id))
+}
+
+async fn is_nsfw_channel(ctx: &HybridContext) -> bool {
+    if let Some(nsfw) = ctx
+        .interaction()
+        .and_then(|interaction| interaction.channel.as_ref()?.nsfw)
+    {
+        return nsfw;
+    }
+
+    let Some(channel_id) = ctx.channel_id() else {
+        return false;
+    };
+
+    fetch_channel(channel_id)
+        .await
+        .ok()
+        .and_then(|channel| channel.nsfw)
+        .unwrap_or(false)
+}
+
+async fn bot_permissions(ctx: &HybridContext) -> Result<u64, DescordError> {
+    // sent with every interaction
+    if let Some(interaction) = ctx.interaction() {
+        return Ok(interaction.app_permissions.parse().unwrap_or_default());
+    }
+
+    let (Some(guild_id), Some(channel_id)) = (ctx.guild_id(), ctx.channel_id()) else {
+        // no permissions in DMs
+        return Ok(ADMINISTRATOR);
+    };
+
+    let bot_id = BOT_ID.lock().unwrap().clone().unwrap_or_default();
+    let member = fetch_member(guild_id, &bot_id)
+        .await
+        .map_err(|e| e.to_string());
+
+    match member {
+        Ok(member) => member_permissions(guild_id, channel_id, &member).await,
+        Err(e) => {
+            log::error!("Failed to fetch the bot's member: {e}");
+            Err(fail("Couldn't check permissions, try again later"))
+        }
+    }
+}
@@ -0,0 +1,91 @@
+use super::*;
+
+/// Parameters shared between message and slash commands.
+///
+/// Derive it with `#[derive(CommandArgs)]` and take the struct as the last
+/// parameter of a `#[command]` or `#[slash]` function, marked with `#[args]`.
+/// Fields accept the same attributes as message command parameters
+/// (`#[named]`, `#[flag]`, `#[default = ...]`) and slash command options
+/// (`#[min_value]`, `#[max_length]`, `#[choice]`, ...), doc comments are
+/// used as option descriptions. Every field must be a type slash command
+/// options support, unless the struct is marked `#[command_args(message_only)]`,
+/// which allows any argument type but can't be used by slash or hybrid commands.
+///
+/// # Examples
+///
+/// ```ignore
+/// #[derive(CommandArgs)]
+/// struct BanArgs {
+///     /// The user to ban
+///     user: User,
+///     /// Days of messages to delete
+///     #[named]
+///     #[default = 0]
+///     #[min_value = 0]
+///     #[max_value = 7]
+///     days: isize,
+///     /// Why they are banned
+///     reason: Option<String>,
+/// }
+///
+/// #[command]
+/// async fn ban(msg: Message, #[args] args: BanArgs) {
+///     // ...
+/// }
+///
+/// #[slash(description = "Ban a user")]
+/// async fn ban_slash(interaction: Interaction, #[args] args: BanArgs) {
+///     // ...
+/// }
+/// ```
+pub trait CommandArgs: Sized + Send + 'static {
+    /// `true` for `#[command_args(message_only)]` structs, slash commands refuse them.
+    const MESSAGE_ONLY: bool = false;
+
+    /// Appends the fields to the parameters of a message command.
+    fn add_params(command: &mut Command);
+
+    /// Appends the fields to the options of a slash command.
+    fn add_options(command: &mut SlashCommand);
+
+    /// Takes the fields out of a message command's parsed arguments.
+    fn from_args(args: &mut impl Iterator<Item = Value>) -> Self;
+
+    /// Takes the fields out of a slash command's options.
+    fn from_options(args: &mut impl Iterator<Item = Value>) -> Self;
+}
+
+/// A type that can be a slash command option.
+pub trait OptionValue: Sized {
+    const PARAM_TYPE: ParamType;
+
+    /// Takes the option's value, `None` if it was omitted.
+    fn from_value(value: Value) -> Option<Self>;
+}
+
+macro_rules! option_value {
+    [ $($ty:ty => $variant:ident, $option_variant:ident),* $(,)? ] => {
+        $(
+            impl OptionValue for $ty {
+                const PARAM_TYPE: ParamType = ParamType::$variant;
+
+                fn from_value(value: Value) -> Option<Self> {
+                    match value {
+                        Value::$variant(value) => Some(value),
+                        Value::$option_variant(value) => value,
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+option_value![
+    String => String, StringOption,
+    isize => Int, IntOption,
+    f64 => Number, NumberOption,
+    bool => Bool, BoolOption,
+    Channel => Channel, ChannelOption,
+    User => User, UserOption,
+];
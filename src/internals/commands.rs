@@ -1,107 +1,151 @@
 use super::*;
 
+/// How an argument is passed to a message command parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParamKind {
+    /// Passed by position.
+    Positional,
+    /// Passed as `--name value`, `--name=value` or `name=value`.
+    Named,
+    /// A `bool` that is `true` when `--name` is passed.
+    Flag,
+}
+
 #[derive(Debug, Clone)]
 pub struct Command {
     pub name: String,
-    pub custom_prefix: bool,
+    /// Other names the command can be invoked with.
+    pub aliases: Vec<String>,
+    /// A prefix used instead of the client's prefixes.
+    pub prefix: Option<String>,
+    /// The category the command is listed under in the help command.
+    pub category: Option<String>,
     pub fn_sig: Vec<ParamType>,
+    pub fn_param_names: Vec<String>,
+    pub fn_param_kinds: Vec<ParamKind>,
+    /// Type names shown in the usage line, see [`FromArgument::type_name`].
+    pub fn_param_type_names: Vec<&'static str>,
+    /// Checked before parsing the arguments.
+    pub fn_param_constraints: Vec<OptionConstraints>,
+    /// Parsers for each parameter, `None` for `Args` which takes the remaining arguments.
+    pub parsers: Vec<Option<ArgumentParser>>,
     pub handler_fn: HandlerFn,
     pub optional_params: Vec<bool>,
     pub permissions: Vec<String>,
     pub description: String,
+    pub cooldown: Option<Cooldown>,
+    /// Overrides the client's handler timeout for this command.
+    pub timeout: Option<std::time::Duration>,
+    pub checks: Checks,
 }
 
 impl Command {
-    pub async fn call(&self, data: Message) -> DescordResult {
-        let split = parse_args(&data.content);
+    /// Returns how the command is used, e.g. `!ban <user: user> [--days <integer>]`.
+    pub fn usage(&self) -> String {
+        let mut usage = self.name.clone();
+
+        for (idx, name) in self.fn_param_names.iter().enumerate() {
+            let type_name = self.fn_param_type_names[idx];
+            let option = name.replace('_', "-");
+
+            let param = match self.fn_param_kinds[idx] {
+                _ if self.fn_sig[idx] == ParamType::Args => format!("{name}..."),
+                ParamKind::Positional => format!("{name}: {type_name}"),
+                ParamKind::Named => format!("--{option} <{type_name}>"),
+                ParamKind::Flag => format!("--{option}"),
+            };
+
+            usage += &match self.fn_param_kinds[idx] {
+                ParamKind::Positional if !self.optional_params[idx] => format!(" <{param}>"),
+                ParamKind::Named if !self.optional_params[idx] => format!(" {param}"),
+                _ => format!(" [{param}]"),
+            };
+        }
+
+        usage
+    }
+
+    /// Runs the command, `args` is the part of the message after the command name.
+    pub async fn call(&self, data: Message, args: &str) -> DescordResult {
+        if !has_middleware() {
+            return self.invoke(data, args).await;
+        }
+
+        let invocation = self.invocation(&data);
+        let command = self.clone();
+        let args = args.to_owned();
+        run_middleware(
+            invocation,
+            Box::pin(async move { command.invoke(data, &args).await }),
+        )
+        .await
+    }
+
+    /// Describes a call to the command, for middleware and error reporting.
+    pub(crate) fn invocation(&self, data: &Message) -> Invocation {
+        Invocation::Command {
+            name: self.name.clone(),
+            message: data.clone(),
+        }
+    }
+
+    async fn invoke(&self, data: Message, args: &str) -> DescordResult {
+        check_member_permissions(&data, &self.permissions).await?;
+        self.checks.run(&data.clone().into()).await?;
+
+        let (positional, mut named) = self.split_args(args)?;
+        let mut positional = positional.into_iter();
         let mut args: Vec<Value> = Vec::with_capacity(self.fn_sig.len());
 
-        let mut idx = 1;
-        while idx - 1 < self.fn_sig.len() {
-            let ty = &self.fn_sig[idx - 1];
-            let optional = self.optional_params[idx - 1];
-            if idx < split.len() {
-                match ty {
-                    ParamType::String => args.push(if optional {
-                        Value::StringOption(Some(split[idx].to_owned()))
-                    } else {
-                        Value::String(split[idx].to_owned())
-                    }),
-                    ParamType::Int => args.push(if optional {
-                        Value::IntOption(Some(split[idx].parse::<isize>().unwrap()))
-                    } else {
-                        Value::Int(split[idx].parse::<isize>().unwrap())
-                    }),
-                    ParamType::Bool => args.push(if optional {
-                        Value::BoolOption(Some(split[idx].parse::<bool>().unwrap()))
-                    } else {
-                        Value::Bool(split[idx].parse::<bool>().unwrap())
-                    }),
-                    ParamType::Channel => {
-                        let channel_id_str = &split[idx];
-                        let channel_id =
-                            if channel_id_str.starts_with("<#") && channel_id_str.ends_with(">") {
-                                &channel_id_str[2..channel_id_str.len() - 1]
-                            } else {
-                                channel_id_str
-                            };
-                        match fetch_channel(channel_id).await {
-                            Ok(channel) => args.push(if optional {
-                                Value::ChannelOption(Some(channel))
-                            } else {
-                                Value::Channel(channel)
-                            }),
-                            Err(_) => {
-                                if !optional {
-                                    panic!("Channel not found")
-                                }
-                            }
-                        }
-                    }
-                    ParamType::User => {
-                        let user_id_str = &split[idx];
-                        let user_id = if user_id_str.starts_with("<@") && user_id_str.ends_with(">")
-                        {
-                            &user_id_str[2..user_id_str.len() - 1]
-                        } else {
-                            user_id_str
-                        };
-                        match fetch_user(user_id).await {
-                            Ok(user) => args.push(if optional {
-                                Value::UserOption(Some(user))
-                            } else {
-                                Value::User(user)
-                            }),
-                            Err(_) => {
-                                if !optional {
-                                    panic!("User not found")
-                                }
-                            }
-                        }
-                    }
-                    ParamType::Args => {
-                        args.push(Value::Args(
-                            split[idx..].iter().map(|i| i.to_string()).collect(),
-                        ));
+        for (idx, parser) in self.parsers.iter().enumerate() {
+            let Some(parser) = parser else {
+                args.push(Value::Args(positional.by_ref().collect()));
+                continue;
+            };
+
+            let mut arg = match self.fn_param_kinds[idx] {
+                ParamKind::Positional => positional.next(),
+                ParamKind::Named | ParamKind::Flag => named.remove(&idx),
+            };
+
+            if let Some(value) = &mut arg {
+                match self.fn_param_constraints[idx].check(value) {
+                    Ok(checked) => *value = checked.to_string(),
+                    Err(reason) => {
+                        return Err(Box::new(DescordError::InvalidArgument {
+                            name: self.fn_param_names[idx].clone(),
+                            reason,
+                            usage: self.usage(),
+                        }))
                     }
-                    _ => {}
                 }
-            } else if optional {
-                match ty {
-                    ParamType::String => args.push(Value::StringOption(None)),
-                    ParamType::Int => args.push(Value::IntOption(None)),
-                    ParamType::Bool => args.push(Value::BoolOption(None)),
-                    ParamType::Channel => args.push(Value::ChannelOption(None)),
-                    ParamType::User => args.push(Value::UserOption(None)),
-                    _ => {}
+            }
+
+            if arg.is_none() && !self.optional_params[idx] {
+                return Err(Box::new(DescordError::MissingRequiredArgument {
+                    name: self.fn_param_names[idx].clone(),
+                    usage: self.usage(),
+                }));
+            }
+
+            match parser(arg.as_deref(), &data).await {
+                Ok(value) => args.push(value),
+                Err(reason) => {
+                    return Err(Box::new(DescordError::InvalidArgument {
+                        name: self.fn_param_names[idx].clone(),
+                        reason,
+                        usage: self.usage(),
+                    }))
                 }
-            } else {
-                return Err(Box::new(DescordError::MissingRequiredArgument(
-                    self.name.clone(),
-                )));
             }
+        }
 
-            idx += 1;
+        // invalid arguments don't use up the cooldown
+        if let Some(cooldown) = &self.cooldown {
+            let user_id = data.author.as_ref().map(|author| author.id.as_str());
+            cooldown
+                .check(user_id, Some(&data.channel_id), data.guild_id.as_deref())
+                .map_err(Cooldown::error)?;
         }
 
         let fut = ((self.handler_fn)(data, args));
@@ -113,4 +157,479 @@ impl Command {
 
         Ok(())
     }
+
+    /// Splits the arguments into positional ones and named ones by parameter index.
+    fn split_args(
+        &self,
+        args: &str,
+    ) -> Result<(Vec<String>, HashMap<usize, String>), DescordError> {
+        let mut tokens = lex(args).into_iter();
+        let mut positional = vec![];
+        let mut named = HashMap::new();
+
+        if !self.fn_param_kinds.contains(&ParamKind::Named)
+            && !self.fn_param_kinds.contains(&ParamKind::Flag)
+        {
+            return Ok((tokens.map(|token| token.text).collect(), named));
+        }
+
+        while let Some(token) = tokens.next() {
+            if token.quoted {
+                positional.push(token.text);
+                continue;
+            }
+
+            // everything after `--` is positional
+            if token.text == "--" {
+                positional.extend(tokens.by_ref().map(|token| token.text));
+                break;
+            }
+
+            let (key, value) = match token.text.strip_prefix("--") {
+                Some(option) => match option.split_once('=') {
+                    Some((key, value)) => (key, Some(value)),
+                    None => (option, None),
+                },
+
+                None => match token.text.split_once('=') {
+                    Some((key, value)) if self.find_named(key).is_some() => (key, Some(value)),
+                    _ => {
+                        positional.push(token.text);
+                        continue;
+                    }
+                },
+            };
+
+            let Some(idx) = self.find_named(key) else {
+                return Err(DescordError::InvalidArgument {
+                    name: format!("--{key}"),
+                    reason: "unknown option".to_string(),
+                    usage: self.usage(),
+                });
+            };
+
+            let value = match (value, self.fn_param_kinds[idx]) {
+                (Some(value), _) => value.to_string(),
+                (None, ParamKind::Flag) => "true".to_string(),
+                (None, _) => match tokens.next() {
+                    Some(token) => token.text,
+                    None => {
+                        return Err(DescordError::InvalidArgument {
+                            name: self.fn_param_names[idx].clone(),
+                            reason: format!("expected a value after `--{key}`"),
+                            usage: self.usage(),
+                        })
+                    }
+                },
+            };
+
+            named.insert(idx, value);
+        }
+
+        Ok((positional, named))
+    }
+
+    /// Returns the index of the named or flag parameter called `key`.
+    fn find_named(&self, key: &str) -> Option<usize> {
+        let key = key.replace('-', "_");
+
+        (0..self.fn_param_names.len()).find(|&idx| {
+            self.fn_param_kinds[idx] != ParamKind::Positional && self.fn_param_names[idx] == key
+        })
+    }
+}
+
+/// The most suggestions [`CommandRouter::suggest`] returns.
+pub const MAX_SUGGESTIONS: usize = 3;
+
+/// Finds the message command a message invokes.
+///
+/// Names are checked before aliases. The command name can be followed by any
+/// whitespace, and messages starting with a mention of the bot can leave out
+/// the prefix, e.g. `@Bot ping`.
+#[derive(Debug, Clone, Default)]
+pub struct CommandRouter {
+    /// Commands using the client's prefixes.
+    commands: CommandTable,
+    /// Commands with their own prefix, by prefix.
+    prefixed: HashMap<String, CommandTable>,
+    pub case_insensitive: bool,
+    pub mention_prefix: bool,
+    /// Limits how often unknown commands get suggestions, `None` disables them.
+    pub suggestions: Option<Cooldown>,
+}
+
+#[derive(Debug, Clone, Default)]
+struct CommandTable {
+    commands: HashMap<String, Command>,
+    /// Alias to command name.
+    aliases: HashMap<String, String>,
+    /// Lowercase name or alias to command name.
+    lowercase: HashMap<String, String>,
+}
+
+impl CommandTable {
+    fn insert(&mut self, command: Command) {
+        for alias in &command.aliases {
+            if self.commands.contains_key(alias) {
+                log::warn!(
+                    "Alias `{alias}` of `{}` is shadowed by a command with the same name",
+                    command.name
+                );
+            }
+
+            self.aliases.insert(alias.clone(), command.name.clone());
+            self.lowercase
+                .insert(alias.to_lowercase(), command.name.clone());
+        }
+
+        self.lowercase
+            .insert(command.name.to_lowercase(), command.name.clone());
+        self.commands.insert(command.name.clone(), command);
+    }
+
+    fn get(&self, name: &str, case_insensitive: bool) -> Option<&Command> {
+        if let Some(command) = self.commands.get(name) {
+            return Some(command);
+        }
+
+        let name = match self.aliases.get(name) {
+            Some(name) => name,
+            None if case_insensitive => self.lowercase.get(&name.to_lowercase())?,
+            None => return None,
+        };
+
+        self.commands.get(name)
+    }
+}
+
+impl CommandRouter {
+    pub fn new() -> Self {
+        Self {
+            mention_prefix: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn insert(&mut self, command: Command) {
+        match &command.prefix {
+            Some(prefix) => self
+                .prefixed
+                .entry(prefix.clone())
+                .or_default()
+                .insert(command),
+            None => self.commands.insert(command),
+        }
+    }
+
+    /// Returns the command called `name`, or one of its aliases.
+    ///
+    /// Only commands using the client's prefixes are checked.
+    pub fn get(&self, name: &str) -> Option<&Command> {
+        self.commands.get(name, self.case_insensitive)
+    }
+
+    /// Returns all registered commands.
+    pub fn commands(&self) -> impl Iterator<Item = &Command> {
+        self.commands.commands.values().chain(
+            self.prefixed
+                .values()
+                .flat_map(|table| table.commands.values()),
+        )
+    }
+
+    /// Returns the command a message invokes along with the rest of the message.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The message content.
+    /// * `prefixes` - The prefixes for this message, see [`resolve_prefixes`].
+    /// * `bot_id` - The id of the bot, used to detect mentions.
+    pub fn find<'a>(
+        &self,
+        content: &'a str,
+        prefixes: &[String],
+        bot_id: Option<&str>,
+    ) -> Option<(&Command, &'a str)> {
+        self.prefixed_tables(content, prefixes, bot_id)
+            .into_iter()
+            .find_map(|(_, content, table)| {
+                let end = content.find(char::is_whitespace).unwrap_or(content.len());
+                let (name, rest) = content.split_at(end);
+
+                Some((table.get(name, self.case_insensitive)?, rest))
+            })
+    }
+
+    /// Returns the commands closest to the unknown command a message tries to invoke, with their prefix.
+    ///
+    /// Names and aliases are compared by edit distance, at most [`MAX_SUGGESTIONS`]
+    /// are returned. Empty if the message doesn't start with a prefix or nothing is close enough.
+    ///
+    /// # Arguments
+    ///
+    /// * `content` - The message content.
+    /// * `prefixes` - The prefixes for this message, see [`resolve_prefixes`].
+    /// * `bot_id` - The id of the bot, used to detect mentions.
+    pub fn suggest(&self, content: &str, prefixes: &[String], bot_id: Option<&str>) -> Vec<String> {
+        let mut suggestions: Vec<(usize, String)> = vec![];
+
+        for (prefix, content, table) in self.prefixed_tables(content, prefixes, bot_id) {
+            let name = content
+                .split(char::is_whitespace)
+                .next()
+                .unwrap_or_default();
+            if name.is_empty() {
+                continue;
+            }
+
+            let name = name.to_lowercase();
+            let max_distance = (name.chars().count() / 3).max(1);
+
+            for known in table.commands.keys().chain(table.aliases.keys()) {
+                let distance = edit_distance(&name, &known.to_lowercase());
+                let suggestion = format!("{prefix}{known}");
+
+                if distance <= max_distance && !suggestions.iter().any(|(_, s)| *s == suggestion) {
+                    suggestions.push((distance, suggestion));
+                }
+            }
+        }
+
+        suggestions.sort();
+        suggestions
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, suggestion)| suggestion)
+            .collect()
+    }
+
+    /// Replies with suggestions if the message tries to invoke an unknown command.
+    ///
+    /// Does nothing if suggestions are disabled, there are none or the cooldown is active.
+    pub(crate) async fn reply_suggestions(
+        &self,
+        message: &Message,
+        prefixes: &[String],
+        bot_id: Option<&str>,
+    ) {
+        let Some(cooldown) = &self.suggestions else {
+            return;
+        };
+
+        let suggestions = self.suggest(&message.content, prefixes, bot_id);
+        if suggestions.is_empty() {
+            return;
+        }
+
+        let user_id = message.author.as_ref().map(|author| author.id.as_str());
+        if cooldown
+            .check(
+                user_id,
+                Some(&message.channel_id),
+                message.guild_id.as_deref(),
+            )
+            .is_err()
+        {
+            return;
+        }
+
+        let suggestions = suggestions
+            .iter()
+            .map(|suggestion| format!("`{suggestion}`"))
+            .collect::<Vec<_>>();
+
+        message
+            .reply(format!(
+                "Unknown command, did you mean {}?",
+                suggestions.join(" or ")
+            ))
+            .await;
+    }
+
+    /// Returns the tables a message can invoke commands from, with the
+    /// matching prefix and the rest of the message after it.
+    fn prefixed_tables<'s: 'p, 'p, 'c>(
+        &'s self,
+        content: &'c str,
+        prefixes: &'p [String],
+        bot_id: Option<&str>,
+    ) -> Vec<(&'p str, &'c str, &'s CommandTable)> {
+        let mentioned = bot_id
+            .filter(|_| self.mention_prefix)
+            .and_then(|id| strip_mention(content, id));
+        let content = mentioned.unwrap_or(content);
+
+        let tables = self
+            .prefixed
+            .iter()
+            .map(|(prefix, table)| (prefix.as_str(), table))
+            .chain(
+                prefixes
+                    .iter()
+                    .map(|prefix| (prefix.as_str(), &self.commands)),
+            );
+
+        // `@Bot !ping` works too
+        let unprefixed = mentioned.map(|_| ("", &self.commands));
+
+        tables
+            .chain(unprefixed)
+            .filter_map(|(prefix, table)| {
+                Some((prefix, self.strip_prefix(content, prefix)?, table))
+            })
+            .collect()
+    }
+
+    fn strip_prefix<'a>(&self, content: &'a str, prefix: &str) -> Option<&'a str> {
+        let start = content.get(..prefix.len())?;
+        let matches =
+            start == prefix || (self.case_insensitive && start.eq_ignore_ascii_case(prefix));
+
+        matches.then(|| &content[prefix.len()..])
+    }
+}
+
+/// Returns the number of insertions, deletions, substitutions and swaps
+/// of adjacent characters needed to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    // distances[i][j] is the distance between the first i chars of `a` and the first j of `b`
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+
+            distances[i][j] = distance;
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+/// Returns the rest of the message if it starts with a mention of `id`.
+fn strip_mention<'a>(content: &'a str, id: &str) -> Option<&'a str> {
+    let rest = content.strip_prefix("<@")?;
+    let rest = rest.strip_prefix('!').unwrap_or(rest);
+    let rest = rest.strip_prefix(id)?.strip_prefix('>')?;
+
+    // a mention alone isn't a command
+    let trimmed = rest.trim_start();
+    (trimmed.len() < rest.len() && !trimmed.is_empty()).then_some(trimmed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(name: &str, aliases: &[&str]) -> Command {
+        fn f(
+            _: Message,
+            _: Vec<Value>,
+        ) -> std::pin::Pin<Box<dyn std::future::Future<Output = DescordResult> + Send + 'static>>
+        {
+            Box::pin(async { Ok(()) })
+        }
+
+        Command {
+            name: name.to_string(),
+            aliases: aliases.iter().map(|alias| alias.to_string()).collect(),
+            prefix: None,
+            category: None,
+            fn_sig: vec![],
+            fn_param_names: vec![],
+            fn_param_kinds: vec![],
+            fn_param_type_names: vec![],
+            fn_param_constraints: vec![],
+            parsers: vec![],
+            handler_fn: f,
+            optional_params: vec![],
+            permissions: vec![],
+            description: String::new(),
+            cooldown: None,
+            timeout: None,
+            checks: Checks::default(),
+        }
+    }
+
+    fn router() -> CommandRouter {
+        let mut router = CommandRouter::new();
+        router.insert(command("ping", &[]));
+        router.insert(command("play", &["p"]));
+        router.insert(command("help", &["commands"]));
+        router
+    }
+
+    #[test]
+    fn measures_edit_distance() {
+        assert_eq!(edit_distance("ping", "ping"), 0);
+        assert_eq!(edit_distance("pnig", "ping"), 1);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn suggests_close_commands() {
+        let prefixes = ["!".to_string()];
+
+        assert_eq!(router().suggest("!pnig", &prefixes, None), ["!ping"]);
+        assert_eq!(
+            router().suggest("!comands me", &prefixes, None),
+            ["!commands"]
+        );
+        assert_eq!(router().suggest("!pin", &prefixes, None), ["!ping"]);
+    }
+
+    #[test]
+    fn suggests_nothing_without_a_prefix_or_a_close_command() {
+        let prefixes = ["!".to_string()];
+
+        assert!(router().suggest("pnig", &prefixes, None).is_empty());
+        assert!(router().suggest("!weather", &prefixes, None).is_empty());
+        assert!(router().suggest("!", &prefixes, None).is_empty());
+    }
+
+    #[test]
+    fn treats_everything_after_double_dash_as_positional() {
+        let mut cmd = command("say", &[]);
+        cmd.fn_param_names = vec!["text".to_string(), "loud".to_string()];
+        cmd.fn_param_kinds = vec![ParamKind::Positional, ParamKind::Flag];
+
+        let (positional, named) = cmd.split_args("--loud -- --loud hi").unwrap();
+        assert_eq!(positional, ["--loud", "hi"]);
+        assert_eq!(named.get(&1).map(String::as_str), Some("true"));
+
+        let (positional, named) = cmd.split_args(r#""--loud" hi"#).unwrap();
+        assert_eq!(positional, ["--loud", "hi"]);
+        assert!(named.is_empty());
+    }
+
+    #[test]
+    fn limits_suggestions() {
+        let mut router = CommandRouter::new();
+        for name in ["aa", "ab", "ac", "ad", "ae"] {
+            router.insert(command(name, &[]));
+        }
+
+        assert_eq!(
+            router.suggest("!af", &["!".to_string()], None).len(),
+            MAX_SUGGESTIONS
+        );
+    }
 }
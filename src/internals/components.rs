@@ -2,19 +2,89 @@ use super::*;
 
 pub type ComponentHandlerFn = fn(
     Interaction,
+    Vec<Value>,
 ) -> std::pin::Pin<
     Box<dyn futures_util::Future<Output = DescordResult> + Send + 'static>,
 >;
 
 #[derive(Debug, Clone)]
 pub struct ComponentHandler {
+    /// The custom id this handler responds to.
+    ///
+    /// `{name}` placeholders capture a part of the custom id,
+    /// e.g. `ticket:{ticket_id}:close` matches `ticket:1234:close`.
     pub id: String,
+    /// Whether this handler runs for custom ids that no other handler matched.
+    pub fallback: bool,
+    pub fn_sig: Vec<ParamType>,
+    pub fn_param_names: Vec<String>,
     pub handler_fn: ComponentHandlerFn,
+    pub checks: Checks,
 }
 
 impl ComponentHandler {
-    pub async fn call(&self, data: Interaction) -> DescordResult {
-        let fut = ((self.handler_fn)(data));
+    /// Returns `true` if the id contains placeholders.
+    pub fn is_pattern(&self) -> bool {
+        self.id.contains('{')
+    }
+
+    /// Matches a custom id against this handler's id, returning the parsed parameters.
+    ///
+    /// Returns `None` if the id doesn't match or a captured part can't
+    /// be parsed into the parameter's type.
+    pub fn match_id(&self, custom_id: &str) -> Option<Vec<Value>> {
+        let captures = match_pattern(&self.id, custom_id)?;
+
+        self.fn_param_names
+            .iter()
+            .zip(&self.fn_sig)
+            .map(|(name, ty)| {
+                let capture = *captures.get(name.as_str())?;
+
+                Some(match ty {
+                    ParamType::String => Value::String(capture.to_string()),
+                    ParamType::Int => Value::Int(capture.parse().ok()?),
+                    ParamType::Number => Value::Number(capture.parse().ok()?),
+                    ParamType::Bool => Value::Bool(capture.parse().ok()?),
+                    _ => return None,
+                })
+            })
+            .collect()
+    }
+
+    pub async fn call(&self, data: Interaction, args: Vec<Value>) -> DescordResult {
+        if !has_middleware() {
+            return self.invoke(data, args).await;
+        }
+
+        let invocation = self.invocation(&data);
+        let handler = self.clone();
+        run_middleware(
+            invocation,
+            Box::pin(async move { handler.invoke(data, args).await }),
+        )
+        .await
+    }
+
+    /// Describes a call to the handler, for middleware and error reporting.
+    pub(crate) fn invocation(&self, data: &Interaction) -> Invocation {
+        let custom_id = data
+            .data
+            .as_ref()
+            .and_then(|data| data.custom_id.as_deref())
+            .unwrap_or_default();
+
+        // without the component state
+        Invocation::Component {
+            custom_id: crate::utils::strip_state(custom_id).to_string(),
+            interaction: data.clone(),
+        }
+    }
+
+    async fn invoke(&self, data: Interaction, args: Vec<Value>) -> DescordResult {
+        self.checks.run(&data.clone().into()).await?;
+
+        let fut = ((self.handler_fn)(data, args));
         let boxed_fut: std::pin::Pin<
             Box<dyn std::future::Future<Output = DescordResult> + Send + 'static>,
         > = Box::pin(fut);
@@ -22,3 +92,170 @@ impl ComponentHandler {
         boxed_fut.await
     }
 }
+
+/// Finds the handler for a component's custom id.
+///
+/// Exact ids are checked first, then patterns in the order they were
+/// registered, then the fallback handler.
+#[derive(Debug, Clone, Default)]
+pub struct ComponentRouter {
+    exact: HashMap<String, ComponentHandler>,
+    patterns: Vec<ComponentHandler>,
+    fallback: Option<ComponentHandler>,
+}
+
+impl ComponentRouter {
+    /// Registers a handler, panicking if its id or pattern is already registered.
+    pub fn insert(&mut self, handler: ComponentHandler) {
+        if handler.fallback {
+            if self.fallback.is_some() {
+                panic!("A fallback component handler is already registered");
+            }
+
+            self.fallback = Some(handler);
+        } else if handler.is_pattern() {
+            if self.patterns.iter().any(|pattern| pattern.id == handler.id) {
+                panic!(
+                    "A component handler for `{}` is already registered",
+                    handler.id
+                );
+            }
+
+            self.patterns.push(handler);
+        } else {
+            if self.exact.contains_key(&handler.id) {
+                panic!(
+                    "A component handler for `{}` is already registered",
+                    handler.id
+                );
+            }
+
+            self.exact.insert(handler.id.clone(), handler);
+        }
+    }
+
+    /// Returns the handler for a custom id along with its parsed parameters.
+    pub fn find(&self, custom_id: &str) -> Option<(&ComponentHandler, Vec<Value>)> {
+        // component state is decoded by the handler
+        let custom_id = crate::utils::strip_state(custom_id);
+
+        if let Some(handler) = self.exact.get(custom_id) {
+            return Some((handler, vec![]));
+        }
+
+        self.patterns
+            .iter()
+            .find_map(|handler| Some((handler, handler.match_id(custom_id)?)))
+            .or_else(|| self.fallback.as_ref().map(|handler| (handler, vec![])))
+    }
+}
+
+/// Matches `id` against a pattern, returning the captured parts by placeholder name.
+///
+/// A placeholder captures everything up to the first occurrence of the literal
+/// text following it, or the rest of the id if it is the last part of the pattern.
+/// Captures are never empty.
+fn match_pattern<'p, 'i>(
+    mut pattern: &'p str,
+    mut id: &'i str,
+) -> Option<HashMap<&'p str, &'i str>> {
+    let mut captures = HashMap::new();
+
+    while let Some(start) = pattern.find('{') {
+        id = id.strip_prefix(&pattern[..start])?;
+
+        let end = start + pattern[start..].find('}')?;
+        let name = &pattern[start + 1..end];
+        pattern = &pattern[end + 1..];
+
+        let literal = &pattern[..pattern.find('{').unwrap_or(pattern.len())];
+        let capture_len = if literal.is_empty() {
+            id.len()
+        } else if literal.len() == pattern.len() {
+            // last placeholder, the literal must be a suffix
+            id.strip_suffix(literal)?.len()
+        } else {
+            let first = id.chars().next()?.len_utf8();
+            first + id[first..].find(literal)?
+        };
+
+        if capture_len == 0 {
+            return None;
+        }
+
+        captures.insert(name, &id[..capture_len]);
+        id = &id[capture_len..];
+    }
+
+    (pattern == id).then_some(captures)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn handler(id: &str, fallback: bool) -> ComponentHandler {
+        fn f(
+            _: Interaction,
+            _: Vec<Value>,
+        ) -> std::pin::Pin<Box<dyn std::future::Future<Output = DescordResult> + Send + 'static>>
+        {
+            Box::pin(async { Ok(()) })
+        }
+
+        let names: Vec<String> = match_pattern(id, id)
+            .map(|captures| captures.keys().map(|name| name.to_string()).collect())
+            .unwrap_or_default();
+
+        ComponentHandler {
+            id: id.to_string(),
+            fallback,
+            fn_sig: vec![ParamType::String; names.len()],
+            fn_param_names: names,
+            handler_fn: f,
+            checks: Checks::default(),
+        }
+    }
+
+    #[test]
+    fn matches_placeholders() {
+        let captures = match_pattern("ticket:{id}:close", "ticket:1234:close").unwrap();
+        assert_eq!(captures["id"], "1234");
+
+        let captures = match_pattern("{a}-{b}", "x-y-z").unwrap();
+        assert_eq!((captures["a"], captures["b"]), ("x", "y-z"));
+
+        assert!(match_pattern("ticket:{id}:close", "ticket::close").is_none());
+        assert!(match_pattern("ticket:{id}:close", "ticket:1234:open").is_none());
+        assert!(match_pattern("ticket:{id}", "other:1234").is_none());
+    }
+
+    #[test]
+    fn checks_exact_ids_then_patterns_then_the_fallback() {
+        let mut router = ComponentRouter::default();
+        router.insert(handler("ticket:{id}", false));
+        router.insert(handler("ticket:new", false));
+        router.insert(handler("unknown", true));
+
+        assert_eq!(router.find("ticket:new").unwrap().0.id, "ticket:new");
+        assert_eq!(router.find("ticket:12").unwrap().0.id, "ticket:{id}");
+        assert_eq!(router.find("something").unwrap().0.id, "unknown");
+    }
+
+    #[test]
+    fn keeps_separators_that_are_not_state() {
+        let mut router = ComponentRouter::default();
+        router.insert(handler("tag:{name}", false));
+
+        let (_, args) = router.find("tag:a~b").unwrap();
+        assert!(matches!(&args[..], [Value::String(name)] if name == "a~b"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_duplicate_ids() {
+        let mut router = ComponentRouter::default();
+        router.insert(handler("ticket:{id}", false));
+        router.insert(handler("ticket:{id}", false));
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use super::*;
+
+/// Number of tracked buckets after which expired ones are cleaned up.
+const CLEANUP_THRESHOLD: usize = 1000;
+
+/// Builds the message sent when a command is on cooldown.
+pub type CooldownMessageFn = Arc<dyn Fn(Duration) -> String + Send + Sync>;
+
+lazy_static::lazy_static! {
+    static ref COOLDOWN_MESSAGE: RwLock<Option<CooldownMessageFn>> = RwLock::new(None);
+}
+
+/// Sets the message sent when a command is on cooldown, `None` for the default one.
+pub(crate) fn set_cooldown_message(message: Option<CooldownMessageFn>) {
+    *COOLDOWN_MESSAGE.write().unwrap() = message;
+}
+
+/// Who shares a cooldown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CooldownBucket {
+    /// Each user has their own cooldown.
+    User,
+    /// Each user has their own cooldown in every guild.
+    Member,
+    /// Everyone in a channel shares the cooldown.
+    Channel,
+    /// Everyone in a guild shares the cooldown, DMs are limited per channel.
+    Guild,
+    /// Everyone shares the cooldown.
+    Global,
+}
+
+/// Limits how often a command can be used.
+///
+/// Allows `burst` uses per `duration` in each bucket.
+#[derive(Debug, Clone)]
+pub struct Cooldown {
+    pub duration: Duration,
+    pub bucket: CooldownBucket,
+    pub burst: u32,
+    /// Recent uses by bucket key.
+    uses: Arc<Mutex<HashMap<String, VecDeque<Instant>>>>,
+}
+
+impl Cooldown {
+    pub fn new(duration: Duration, bucket: CooldownBucket, burst: u32) -> Self {
+        Self {
+            duration,
+            bucket,
+            burst: burst.max(1),
+            uses: Default::default(),
+        }
+    }
+
+    /// Creates a cooldown from a duration like `5s` or `1m30s`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the duration is invalid.
+    pub fn parse(duration: &str, bucket: CooldownBucket, burst: u32) -> Self {
+        let Some(parsed) = parse_duration(duration) else {
+            panic!("Invalid cooldown `{duration}`, expected something like `5s` or `1m30s`");
+        };
+
+        Self::new(parsed, bucket, burst)
+    }
+
+    /// Records a use, returning the time remaining if the bucket is on cooldown.
+    pub fn check(
+        &self,
+        user_id: Option<&str>,
+        channel_id: Option<&str>,
+        guild_id: Option<&str>,
+    ) -> Result<(), Duration> {
+        let key = match self.bucket {
+            CooldownBucket::User => user_id.unwrap_or_default().to_string(),
+            CooldownBucket::Member => format!(
+                "{}:{}",
+                guild_id.or(channel_id).unwrap_or_default(),
+                user_id.unwrap_or_default()
+            ),
+            CooldownBucket::Channel => channel_id.unwrap_or_default().to_string(),
+            CooldownBucket::Guild => guild_id.or(channel_id).unwrap_or_default().to_string(),
+            CooldownBucket::Global => String::new(),
+        };
+
+        let now = Instant::now();
+        let mut uses = self.uses.lock().unwrap();
+
+        if uses.len() > CLEANUP_THRESHOLD {
+            uses.retain(|_, times| {
+                times
+                    .back()
+                    .is_some_and(|time| now.duration_since(*time) < self.duration)
+            });
+        }
+
+        let times = uses.entry(key).or_default();
+        while times
+            .front()
+            .is_some_and(|time| now.duration_since(*time) >= self.duration)
+        {
+            times.pop_front();
+        }
+
+        if times.len() >= self.burst as usize {
+            let oldest = times[0];
+            return Err(self.duration - now.duration_since(oldest));
+        }
+
+        times.push_back(now);
+        Ok(())
+    }
+
+    /// Returns the error for a command that is on cooldown for `remaining`.
+    pub(crate) fn error(remaining: Duration) -> DescordError {
+        let message = COOLDOWN_MESSAGE.read().unwrap().clone();
+        let message = match message {
+            Some(message) => message(remaining),
+            None => format!(
+                "This command is on cooldown, try again in {}",
+                format_duration(remaining)
+            ),
+        };
+
+        DescordError::OnCooldown { remaining, message }
+    }
+}
+
+/// Formats a duration like `1m 30s`, rounding up to whole seconds.
+pub fn format_duration(duration: Duration) -> String {
+    let mut seconds = duration.as_secs() + u64::from(duration.subsec_nanos() > 0);
+    let mut parts = vec![];
+
+    for (unit, length) in [("d", 24 * 60 * 60), ("h", 60 * 60), ("m", 60)] {
+        if seconds >= length {
+            parts.push(format!("{}{unit}", seconds / length));
+            seconds %= length;
+        }
+    }
+
+    if seconds > 0 || parts.is_empty() {
+        parts.push(format!("{seconds}s"));
+    }
+
+    parts.join(" ")
+}
@@ -0,0 +1,205 @@
+use std::error::Error;
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use futures_util::future::BoxFuture;
+use log::*;
+
+use super::*;
+
+/// Handles errors returned by handlers, see [`Client::on_error`].
+///
+/// [`Client::on_error`]: crate::Client::on_error
+pub type ErrorHandler = Arc<dyn Fn(ErrorContext) -> BoxFuture<'static, ()> + Send + Sync>;
+
+lazy_static::lazy_static! {
+    static ref ERROR_HANDLER: RwLock<Option<ErrorHandler>> = RwLock::new(None);
+    static ref HANDLER_TIMEOUT: RwLock<Option<Duration>> = RwLock::new(None);
+}
+
+pub(crate) fn set_error_handler(handler: Option<ErrorHandler>) {
+    *ERROR_HANDLER.write().unwrap() = handler;
+}
+
+/// Sets how long a handler can run before it is cancelled, `None` disables it.
+pub(crate) fn set_handler_timeout(timeout: Option<Duration>) {
+    *HANDLER_TIMEOUT.write().unwrap() = timeout;
+}
+
+/// An error returned by a handler, along with what was being handled.
+#[derive(Debug)]
+pub struct ErrorContext {
+    pub error: Box<dyn Error + Send + Sync>,
+    pub invocation: Invocation,
+}
+
+impl ErrorContext {
+    /// Returns the error as a [`DescordError`], `None` if the handler returned another error type.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if let Some(DescordError::OnCooldown { .. }) = ctx.descord_error() {
+    ///     return;
+    /// }
+    /// ```
+    pub fn descord_error(&self) -> Option<&DescordError> {
+        self.error.downcast_ref()
+    }
+
+    /// Returns the command name, the component's custom id or the event name.
+    pub fn name(&self) -> String {
+        self.invocation.name()
+    }
+
+    /// Returns the id of the message, interaction or event payload that was being handled.
+    pub fn id(&self) -> Option<String> {
+        self.invocation.id()
+    }
+
+    /// Returns the user who invoked a command or component, `None` for events.
+    pub fn user(&self) -> Option<User> {
+        self.invocation.user()
+    }
+
+    /// Returns the id of the channel a command or component was used in.
+    pub fn channel_id(&self) -> Option<String> {
+        self.invocation.context()?.channel_id().map(str::to_string)
+    }
+
+    /// Returns the id of the guild a command or component was used in, `None` in DMs.
+    pub fn guild_id(&self) -> Option<String> {
+        self.invocation.context()?.guild_id().map(str::to_string)
+    }
+
+    /// Returns the message that invoked a message command.
+    pub fn message(&self) -> Option<&Message> {
+        match &self.invocation {
+            Invocation::Command { message, .. } => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Returns the interaction that invoked a slash command or component.
+    pub fn interaction(&self) -> Option<&Interaction> {
+        match &self.invocation {
+            Invocation::SlashCommand { interaction, .. }
+            | Invocation::Component { interaction, .. } => Some(interaction),
+            _ => None,
+        }
+    }
+
+    /// Reports the error the default way.
+    ///
+    /// Errors caused by the user (missing or invalid arguments, failed checks and
+    /// cooldowns) get a reply, ephemeral for slash commands and components.
+    /// Every other error is logged along with the command name and id, panics,
+    /// timeouts and errors in slash commands and components also get a generic
+    /// reply so the user isn't left waiting.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// client.on_error(|ctx| async move {
+    ///     // stay quiet about cooldowns
+    ///     if let Some(DescordError::OnCooldown { .. }) = ctx.descord_error() {
+    ///         return;
+    ///     }
+    ///
+    ///     ctx.report().await;
+    /// });
+    /// ```
+    pub async fn report(&self) {
+        let id = self.id().unwrap_or_default();
+
+        // there's no one to reply to
+        if let Invocation::Event { event, .. } = &self.invocation {
+            error!("The {event:?} handler failed ({id}): {}", self.error);
+            return;
+        }
+
+        let response = match self.descord_error() {
+            Some(
+                DescordError::MissingRequiredArgument { .. }
+                | DescordError::InvalidArgument { .. }
+                | DescordError::CheckFailed(_)
+                | DescordError::OnCooldown { .. },
+            ) => self.error.to_string(),
+
+            // the panic message is meant for developers
+            Some(DescordError::Panicked(_) | DescordError::TimedOut(_)) => {
+                error!("{} failed ({id}): {}", self.name(), self.error);
+                String::from("Something went wrong")
+            }
+
+            // interactions show as failed unless they get a response
+            _ => {
+                error!("{} failed ({id}): {}", self.name(), self.error);
+
+                if let Invocation::Command { .. } = &self.invocation {
+                    return;
+                }
+
+                String::from("Something went wrong")
+            }
+        };
+
+        let result = match &self.invocation {
+            Invocation::Command { message, .. } => {
+                utils::send(&message.channel_id, Some(&message.id), response).await;
+                Ok(())
+            }
+
+            Invocation::SlashCommand { interaction, .. }
+            | Invocation::Component { interaction, .. } => interaction.reply(response, true).await,
+
+            Invocation::Event { .. } => Ok(()),
+        };
+
+        if let Err(e) = result {
+            error!("Failed to report an error to the user: {e}");
+        }
+    }
+}
+
+/// Passes an error returned by a handler to the error handler, or reports it the default way.
+pub(crate) async fn handle_error(invocation: Invocation, error: Box<dyn Error + Send + Sync>) {
+    let ctx = ErrorContext { error, invocation };
+    let handler = ERROR_HANDLER.read().unwrap().clone();
+
+    match handler {
+        Some(handler) => handler(ctx).await,
+        None => ctx.report().await,
+    }
+}
+
+/// Runs a handler, turning panics and timeouts into errors.
+///
+/// `timeout` overrides the global handler timeout.
+pub(crate) async fn guard(
+    timeout: Option<Duration>,
+    handler: impl Future<Output = DescordResult>,
+) -> DescordResult {
+    let handler = AssertUnwindSafe(handler).catch_unwind();
+    let timeout = timeout.or(*HANDLER_TIMEOUT.read().unwrap());
+
+    let result = match timeout {
+        Some(timeout) => tokio::time::timeout(timeout, handler)
+            .await
+            .map_err(|_| DescordError::TimedOut(timeout))?,
+        None => handler.await,
+    };
+
+    result.map_err(|panic| DescordError::Panicked(panic_message(panic.as_ref())))?
+}
+
+/// Extracts the message from a panic payload.
+pub(crate) fn panic_message(panic: &(dyn std::any::Any + Send)) -> String {
+    panic
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
@@ -30,6 +30,28 @@ pub type EventHandlerFn = fn(
 
 impl EventHandler {
     pub async fn call(&self, data: HandlerValue) -> DescordResult {
+        if !has_middleware() {
+            return self.invoke(data).await;
+        }
+
+        let invocation = self.invocation(&data);
+        let handler = self.clone();
+        run_middleware(
+            invocation,
+            Box::pin(async move { handler.invoke(data).await }),
+        )
+        .await
+    }
+
+    /// Describes a call to the handler, for middleware and error reporting.
+    pub(crate) fn invocation(&self, data: &HandlerValue) -> Invocation {
+        Invocation::Event {
+            event: self.event,
+            data: data.clone(),
+        }
+    }
+
+    async fn invoke(&self, data: HandlerValue) -> DescordResult {
         let fut = ((self.handler_fn)(data));
         let boxed_fut: std::pin::Pin<
             Box<dyn std::future::Future<Output = DescordResult> + Send + 'static>,
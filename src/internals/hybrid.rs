@@ -0,0 +1,19 @@
+use super::*;
+
+/// A command that is registered both as a message command and as a slash command.
+///
+/// Created with `#[descord::hybrid]`, `register_all!` registers both halves.
+/// When registering manually, pass the halves along with the other commands,
+/// registering slash commands removes the ones that aren't in the list.
+///
+/// # Examples
+///
+/// ```ignore
+/// client.register_commands(vec![remind().command]);
+/// client.register_slash_commands(vec![remind().slash_command]).await;
+/// ```
+#[derive(Debug, Clone)]
+pub struct HybridCommand {
+    pub command: Command,
+    pub slash_command: SlashCommand,
+}
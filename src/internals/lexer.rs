@@ -0,0 +1,149 @@
+/// A single argument of a message command.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub text: String,
+    /// Quoted tokens are never treated as flags or `key=value` pairs.
+    pub quoted: bool,
+}
+
+/// Splits a message into arguments.
+///
+/// Arguments are separated by whitespace, `"double"` or `'single'` quotes at
+/// the start of an argument group text containing whitespace and `\` escapes
+/// a following quote or backslash, elsewhere it is kept as is. A quote without
+/// a matching closing quote is kept as is.
+pub fn lex(input: &str) -> Vec<Token> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = vec![];
+    let mut current: Option<Token> = None;
+    let mut idx = 0;
+
+    while idx < chars.len() {
+        let c = chars[idx];
+        idx += 1;
+
+        if c.is_whitespace() {
+            tokens.extend(current.take());
+            continue;
+        }
+
+        let token = current.get_or_insert_with(|| Token {
+            text: String::new(),
+            quoted: false,
+        });
+
+        match c {
+            '\\' if chars.get(idx).is_some_and(|&c| is_escapable(c)) => {
+                token.text.push(chars[idx]);
+                idx += 1;
+            }
+
+            '"' | '\'' if token.text.is_empty() && !token.quoted => {
+                match find_closing_quote(&chars[idx..], c) {
+                    Some(len) => {
+                        token.text.push_str(&unescape(&chars[idx..idx + len]));
+                        token.quoted = true;
+                        idx += len + 1;
+                    }
+
+                    None => token.text.push(c),
+                }
+            }
+
+            _ => token.text.push(c),
+        }
+    }
+
+    tokens.extend(current);
+    tokens
+}
+
+/// Returns the length of the quoted text, `None` if the quote is never closed.
+fn find_closing_quote(chars: &[char], quote: char) -> Option<usize> {
+    let mut idx = 0;
+    while idx < chars.len() {
+        match chars[idx] {
+            '\\' if chars.get(idx + 1).is_some_and(|&c| is_escapable(c)) => idx += 2,
+            c if c == quote => return Some(idx),
+            _ => idx += 1,
+        }
+    }
+
+    None
+}
+
+fn unescape(chars: &[char]) -> String {
+    let mut text = String::with_capacity(chars.len());
+    let mut chars = chars.iter().peekable();
+
+    while let Some(&c) = chars.next() {
+        match c {
+            '\\' if chars.peek().is_some_and(|&&c| is_escapable(c)) => text.extend(chars.next()),
+            c => text.push(c),
+        }
+    }
+
+    text
+}
+
+/// Returns `true` if a `\` before `c` escapes it.
+fn is_escapable(c: char) -> bool {
+    matches!(c, '"' | '\'' | '\\')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(input: &str) -> Vec<String> {
+        lex(input).into_iter().map(|token| token.text).collect()
+    }
+
+    #[test]
+    fn splits_on_whitespace() {
+        assert_eq!(texts("  a b\tc\n d "), ["a", "b", "c", "d"]);
+        assert!(lex("   ").is_empty());
+    }
+
+    #[test]
+    fn groups_quoted_text() {
+        assert_eq!(texts(r#"a "b c" 'd e'"#), ["a", "b c", "d e"]);
+        assert_eq!(texts(r#""" x"#), ["", "x"]);
+        assert!(lex(r#""b c""#)[0].quoted);
+        assert!(!lex("b")[0].quoted);
+
+        // quotes only group at the start of an argument
+        assert_eq!(texts(r#"it's "fine""#), ["it's", "fine"]);
+    }
+
+    #[test]
+    fn escapes_quotes_and_backslashes() {
+        assert_eq!(texts(r#"\"a b\""#), [r#""a"#, r#"b""#]);
+        assert_eq!(texts(r#""say \"hi\"" \\"#), [r#"say "hi""#, r"\"]);
+        assert_eq!(texts(r"'it\'s'"), ["it's"]);
+    }
+
+    #[test]
+    fn keeps_other_backslashes() {
+        assert_eq!(texts(r"C:\Users\me \n"), [r"C:\Users\me", r"\n"]);
+        assert_eq!(texts(r#""a\nb""#), [r"a\nb"]);
+        assert_eq!(texts(r"trailing\"), [r"trailing\"]);
+    }
+
+    #[test]
+    fn keeps_unterminated_quotes() {
+        assert_eq!(texts(r#""a b"#), [r#""a"#, "b"]);
+        assert_eq!(texts(r#"'a "b c""#), ["'a", "b c"]);
+        assert_eq!(texts(r#""a \""#), [r#""a"#, r#"""#]);
+    }
+
+    #[test]
+    fn double_dash_is_a_plain_token() {
+        let tokens = lex(r#"-- "--" --flag"#);
+        assert_eq!(tokens[0].text, "--");
+        assert!(!tokens[0].quoted);
+        assert_eq!(tokens[1].text, "--");
+        assert!(tokens[1].quoted);
+        assert_eq!(tokens[2].text, "--flag");
+    }
+}
@@ -0,0 +1,140 @@
+use std::sync::{Arc, RwLock};
+
+use futures_util::future::BoxFuture;
+
+use super::*;
+use crate::models::hybrid_context::HybridContext;
+
+/// Runs around handlers, see [`Client::around`].
+///
+/// [`Client::around`]: crate::Client::around
+pub type Middleware =
+    Arc<dyn Fn(Invocation, Next) -> BoxFuture<'static, DescordResult> + Send + Sync>;
+
+lazy_static::lazy_static! {
+    static ref MIDDLEWARE: RwLock<Arc<Vec<Middleware>>> = RwLock::new(Arc::new(vec![]));
+}
+
+/// Replaces the middleware chain, called when a client starts.
+pub(crate) fn set_middleware(middleware: Vec<Middleware>) {
+    *MIDDLEWARE.write().unwrap() = Arc::new(middleware);
+}
+
+/// What is being handled, passed to middleware.
+#[derive(Debug, Clone)]
+pub enum Invocation {
+    Command {
+        name: String,
+        message: Message,
+    },
+    SlashCommand {
+        name: String,
+        interaction: Interaction,
+    },
+    Component {
+        custom_id: String,
+        interaction: Interaction,
+    },
+    Event {
+        event: Event,
+        data: HandlerValue,
+    },
+}
+
+impl Invocation {
+    /// Returns the command name, the component's custom id or the event name.
+    pub fn name(&self) -> String {
+        match self {
+            Invocation::Command { name, .. } | Invocation::SlashCommand { name, .. } => {
+                name.clone()
+            }
+            Invocation::Component { custom_id, .. } => custom_id.clone(),
+            Invocation::Event { event, .. } => format!("{event:?}"),
+        }
+    }
+
+    /// Returns the id of the message, interaction or event payload being handled.
+    pub fn id(&self) -> Option<String> {
+        match self {
+            Invocation::Command { message, .. } => Some(message.id.clone()),
+            Invocation::SlashCommand { interaction, .. }
+            | Invocation::Component { interaction, .. } => Some(interaction.id.clone()),
+            Invocation::Event { data, .. } => match data {
+                HandlerValue::Message(message) => Some(message.id.clone()),
+                HandlerValue::DeletedMessage(message) => Some(message.message_id.clone()),
+                HandlerValue::Reaction(reaction) => Some(reaction.message_id.clone()),
+                HandlerValue::GuildCreate(guild) => Some(guild.id.clone()),
+                HandlerValue::Interaction(interaction) => Some(interaction.id.clone()),
+                HandlerValue::RoleEvent(event) => Some(event.role.id.clone()),
+                HandlerValue::RoleDelete(event) => Some(event.role_id.clone()),
+                HandlerValue::Member(member) => member.user.as_ref().map(|user| user.id.clone()),
+                HandlerValue::MemberLeave(member) => Some(member.user.id.clone()),
+                _ => None,
+            },
+        }
+    }
+
+    /// Returns the message or interaction that invoked a command or component, `None` for events.
+    pub fn context(&self) -> Option<HybridContext> {
+        match self {
+            Invocation::Command { message, .. } => Some(message.clone().into()),
+            Invocation::SlashCommand { interaction, .. }
+            | Invocation::Component { interaction, .. } => Some(interaction.clone().into()),
+            Invocation::Event { .. } => None,
+        }
+    }
+
+    /// Returns the user who invoked a command or component, `None` for events.
+    pub fn user(&self) -> Option<User> {
+        self.context()?.user().cloned()
+    }
+}
+
+/// The rest of the middleware chain, followed by the handler.
+pub struct Next {
+    invocation: Invocation,
+    middleware: Arc<Vec<Middleware>>,
+    idx: usize,
+    handler: BoxFuture<'static, DescordResult>,
+}
+
+impl Next {
+    /// Runs the remaining middleware and the handler, returning the handler's result.
+    pub async fn run(self) -> DescordResult {
+        let Some(middleware) = self.middleware.get(self.idx).cloned() else {
+            return self.handler.await;
+        };
+
+        let invocation = self.invocation.clone();
+        let next = Next {
+            idx: self.idx + 1,
+            ..self
+        };
+
+        middleware(invocation, next).await
+    }
+}
+
+/// Returns `true` if any middleware is registered.
+///
+/// Handlers check this first, running through middleware requires owned clones.
+pub(crate) fn has_middleware() -> bool {
+    !MIDDLEWARE.read().unwrap().is_empty()
+}
+
+/// Runs `handler` through the registered middleware.
+pub(crate) async fn run_middleware(
+    invocation: Invocation,
+    handler: BoxFuture<'static, DescordResult>,
+) -> DescordResult {
+    let middleware = MIDDLEWARE.read().unwrap().clone();
+
+    Next {
+        invocation,
+        middleware,
+        idx: 0,
+        handler,
+    }
+    .run()
+    .await
+}
@@ -1,19 +1,39 @@
 use std::collections::HashMap;
 
+mod arguments;
+mod checks;
+mod command_args;
 mod commands;
 mod components;
+mod cooldown;
+mod error_handler;
 mod events;
+mod hybrid;
+mod lexer;
+mod middleware;
+mod prefix;
+mod rerun;
 mod slash_commands;
 
+pub use arguments::*;
+pub use checks::*;
+pub use command_args::*;
 pub use commands::*;
 pub use components::*;
+pub use cooldown::*;
+pub use error_handler::*;
 pub use events::*;
+pub use hybrid::*;
+pub use lexer::*;
+pub use middleware::*;
+pub use prefix::*;
+pub use rerun::*;
 pub use slash_commands::*;
 
 use crate::consts::events::Event;
 use crate::models::channel::Channel;
 use crate::models::deleted_message_response::DeletedMessage;
-use crate::models::interaction::{Interaction, InteractionData};
+use crate::models::interaction::{Interaction, InteractionAutoCompleteChoice, InteractionData};
 use crate::models::misc::Reconnect;
 use crate::models::reaction_response::Reaction;
 use crate::prelude::*;
@@ -31,8 +51,37 @@ pub type HandlerFn = fn(
 
 #[derive(Error, Debug)]
 pub enum DescordError {
-    #[error("Missing required argument for command: {0}")]
-    MissingRequiredArgument(String),
+    #[error("Missing required argument `{name}`\nUsage: `{usage}`")]
+    MissingRequiredArgument { name: String, usage: String },
+    #[error("Invalid value for `{name}`: {reason}\nUsage: `{usage}`")]
+    InvalidArgument {
+        name: String,
+        reason: String,
+        usage: String,
+    },
+    #[error("Invalid component state: {0}")]
+    InvalidComponentState(String),
+    #[error("Request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Failed to read attachment: {0}")]
+    Attachment(#[from] std::io::Error),
+    #[error("Discord returned {status}: {message}")]
+    Api { status: u16, message: String },
+    #[error("Failed to parse response: {0}")]
+    Deserialize(#[from] nanoserde::DeJsonErr),
+    #[error("The interaction token has expired")]
+    InteractionExpired,
+    #[error("{0}")]
+    CheckFailed(String),
+    #[error("{message}")]
+    OnCooldown {
+        remaining: std::time::Duration,
+        message: String,
+    },
+    #[error("Something went wrong: the handler panicked ({0})")]
+    Panicked(String),
+    #[error("Something went wrong: the handler timed out after {}", format_duration(*.0))]
+    TimedOut(std::time::Duration),
 }
 
 #[macro_export]
@@ -58,6 +107,7 @@ macro_rules! implemented_enum {
 pub enum ParamType {
     String,
     Int,
+    Number,
     Bool,
     Channel,
     User,
@@ -68,6 +118,7 @@ pub enum ParamType {
 pub enum Value {
     String(String),
     Int(isize),
+    Number(f64),
     Bool(bool),
     Channel(Channel),
     User(User),
@@ -75,49 +126,13 @@ pub enum Value {
 
     StringOption(Option<String>),
     IntOption(Option<isize>),
+    NumberOption(Option<f64>),
     BoolOption(Option<bool>),
     ChannelOption(Option<Channel>),
     UserOption(Option<User>),
 
-    None,
-}
-
-fn parse_args(input: &str) -> Vec<String> {
-    let mut args = Vec::new();
-    let mut current_arg = String::new();
-    let mut quote_char = None;
-    let mut chars = input.chars();
-
-    while let Some(c) = chars.next() {
-        match c {
-            ' ' | '\t' if quote_char.is_none() => {
-                if !current_arg.is_empty() {
-                    args.push(current_arg.clone());
-                    current_arg.clear();
-                }
-            }
-            '\'' | '"' => {
-                if quote_char.is_none() {
-                    quote_char = Some(c);
-                    current_arg.push(c);
-                } else if quote_char.unwrap() == c {
-                    quote_char = None;
-                    current_arg.remove(0);
-                } else {
-                    current_arg.push(c);
-                }
-            }
-            _ => current_arg.push(c),
-        }
-    }
+    /// A message command argument parsed with [`FromArgument`].
+    Any(std::sync::Arc<dyn std::any::Any + Send + Sync>),
 
-    if !current_arg.is_empty() {
-        if quote_char.is_some() {
-            args.extend(current_arg.split_whitespace().map(|s| s.to_string()));
-        } else {
-            args.push(current_arg);
-        }
-    }
-
-    args
+    None,
 }
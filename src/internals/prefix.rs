@@ -0,0 +1,45 @@
+use std::sync::{Arc, RwLock};
+
+use futures_util::future::BoxFuture;
+
+use super::*;
+
+/// Returns the prefixes message commands can be used with in reply to a message.
+///
+/// See [`Client::prefix_resolver`].
+///
+/// [`Client::prefix_resolver`]: crate::Client::prefix_resolver
+pub type PrefixResolver = Arc<dyn Fn(Message) -> BoxFuture<'static, Vec<String>> + Send + Sync>;
+
+lazy_static::lazy_static! {
+    static ref DEFAULT_PREFIX: RwLock<String> = RwLock::new(String::new());
+    static ref PREFIX_RESOLVER: RwLock<Option<PrefixResolver>> = RwLock::new(None);
+}
+
+pub(crate) fn set_default_prefix(prefix: &str) {
+    *DEFAULT_PREFIX.write().unwrap() = prefix.to_owned();
+}
+
+pub(crate) fn set_prefix_resolver(resolver: Option<PrefixResolver>) {
+    *PREFIX_RESOLVER.write().unwrap() = resolver;
+}
+
+/// Returns the prefixes message commands can be invoked with in reply to `message`.
+///
+/// Uses the prefix resolver if one is set, the default prefix otherwise.
+pub async fn resolve_prefixes(message: &Message) -> Vec<String> {
+    let resolver = PREFIX_RESOLVER.read().unwrap().clone();
+
+    match resolver {
+        Some(resolver) => resolver(message.clone()).await,
+        None => vec![DEFAULT_PREFIX.read().unwrap().clone()],
+    }
+}
+
+/// Returns the prefix to show to the user in reply to `message`, e.g. in the help command.
+pub async fn resolve_prefix(message: &Message) -> String {
+    match resolve_prefixes(message).await.into_iter().next() {
+        Some(prefix) => prefix,
+        None => DEFAULT_PREFIX.read().unwrap().clone(),
+    }
+}
@@ -0,0 +1,112 @@
+use std::future::Future;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
+
+use super::*;
+use crate::cache::COMMAND_REPLIES;
+
+lazy_static::lazy_static! {
+    static ref RERUN_WINDOW: RwLock<Option<Duration>> = RwLock::new(None);
+}
+
+tokio::task_local! {
+    static RUNNING: Arc<RunningCommand>;
+}
+
+/// Sets how long after being sent an edited message re-runs its command, `None` disables it.
+pub(crate) fn set_rerun_window(window: Option<Duration>) {
+    *RERUN_WINDOW.write().unwrap() = window;
+}
+
+/// A message that can invoke a command again when edited.
+#[derive(Debug, Clone)]
+pub(crate) struct CommandReply {
+    created: Instant,
+    /// The bot's first reply to the last invocation.
+    reply_id: Option<String>,
+}
+
+/// The message command running in the current task.
+#[derive(Debug)]
+struct RunningCommand {
+    message_id: String,
+    /// The reply to edit instead of sending a new one, taken by the first reply.
+    previous: Mutex<Option<String>>,
+    sent: Mutex<Option<String>>,
+}
+
+/// Remembers when a message was sent, if commands are re-run on edits.
+pub(crate) fn track_message(message: &Message) {
+    let from_bot = message.author.as_ref().is_some_and(|author| author.bot);
+    if from_bot || RERUN_WINDOW.read().unwrap().is_none() {
+        return;
+    }
+
+    COMMAND_REPLIES.lock().unwrap().put(
+        message.id.clone(),
+        CommandReply {
+            created: Instant::now(),
+            reply_id: None,
+        },
+    );
+}
+
+/// Returns `true` if an edit of the message should run its command again.
+pub(crate) fn should_rerun(message: &Message) -> bool {
+    let Some(window) = *RERUN_WINDOW.read().unwrap() else {
+        return false;
+    };
+
+    COMMAND_REPLIES
+        .lock()
+        .unwrap()
+        .get(&message.id)
+        .is_some_and(|tracked| tracked.created.elapsed() <= window)
+}
+
+/// Runs a message command, remembering its first reply so a re-run can edit it.
+///
+/// If the command ran before, its first reply to the message edits the previous reply.
+pub(crate) async fn run_tracked<F: Future>(message: &Message, command: F) -> F::Output {
+    let Some(tracked) = COMMAND_REPLIES.lock().unwrap().get(&message.id).cloned() else {
+        return command.await;
+    };
+
+    let running = Arc::new(RunningCommand {
+        message_id: message.id.clone(),
+        previous: Mutex::new(tracked.reply_id),
+        sent: Mutex::new(None),
+    });
+
+    let output = RUNNING.scope(running.clone(), command).await;
+
+    let sent = running.sent.lock().unwrap().take();
+    if let Some(tracked) = COMMAND_REPLIES.lock().unwrap().get_mut(&message.id) {
+        // an edit without a reply keeps the previous one
+        tracked.reply_id = sent.or(tracked.reply_id.take());
+    }
+
+    output
+}
+
+/// Returns the reply to edit instead of sending a new reply to `message_id`.
+pub(crate) fn previous_reply(message_id: &str) -> Option<String> {
+    RUNNING
+        .try_with(|running| {
+            (running.message_id == message_id)
+                .then(|| running.previous.lock().unwrap().take())
+                .flatten()
+        })
+        .ok()
+        .flatten()
+}
+
+/// Remembers a reply to `message_id` if it was sent by the running command.
+pub(crate) fn record_reply(message_id: &str, reply_id: &str) {
+    let _ = RUNNING.try_with(|running| {
+        let mut sent = running.sent.lock().unwrap();
+        if running.message_id == message_id && sent.is_none() {
+            *sent = Some(reply_id.to_string());
+        }
+    });
+}
@@ -1,9 +1,32 @@
 use super::*;
 
-pub(crate) type AutoCompleteFn =
-    fn(
-        String,
-    ) -> std::pin::Pin<Box<dyn futures_util::Future<Output = Vec<String>> + Send + 'static>>;
+pub type AutoCompleteFn = fn(
+    AutoCompleteContext,
+) -> std::pin::Pin<
+    Box<dyn futures_util::Future<Output = Vec<InteractionAutoCompleteChoice>> + Send + 'static>,
+>;
+
+/// Data passed to autocomplete handlers.
+///
+/// Autocomplete handlers can take either this or just a `String` with the
+/// focused option's value.
+#[derive(Debug, Clone)]
+pub struct AutoCompleteContext {
+    /// The autocomplete interaction, contains the user, guild and locale.
+    pub interaction: Interaction,
+    /// Name of the option being autocompleted.
+    pub focused: String,
+    /// What the user has typed in the focused option so far.
+    pub value: String,
+    /// Values of all the options filled in so far (including the focused one), by option name.
+    pub options: HashMap<String, String>,
+}
+
+impl From<AutoCompleteContext> for String {
+    fn from(value: AutoCompleteContext) -> Self {
+        value.value
+    }
+}
 
 pub type SlashHandlerFn = fn(
     Interaction,
@@ -12,10 +35,87 @@ pub type SlashHandlerFn = fn(
     Box<dyn futures_util::Future<Output = DescordResult> + Send + 'static>,
 >;
 
+/// Constraints on a slash command option, enforced by the discord client.
+#[derive(Debug, Clone, Default)]
+pub struct OptionConstraints {
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+    pub min_length: Option<u32>,
+    pub max_length: Option<u32>,
+    /// `(name, value)` pairs the user has to pick from.
+    pub choices: Vec<(String, String)>,
+}
+
+impl OptionConstraints {
+    /// Checks a message command argument against the constraints.
+    ///
+    /// If there are choices the argument can be either a choice's name or its value,
+    /// the value is returned.
+    pub fn check<'a>(&'a self, arg: &'a str) -> Result<&'a str, String> {
+        if !self.choices.is_empty() {
+            return self
+                .choices
+                .iter()
+                .find(|(name, value)| value == arg || name.eq_ignore_ascii_case(arg))
+                .map(|(_, value)| value.as_str())
+                .ok_or_else(|| {
+                    let choices: Vec<_> = self
+                        .choices
+                        .iter()
+                        .map(|(name, _)| format!("`{name}`"))
+                        .collect();
+
+                    format!("expected one of {}", choices.join(", "))
+                });
+        }
+
+        let len = arg.chars().count() as u32;
+        if self.min_length.is_some_and(|min| len < min) {
+            return Err(format!(
+                "must be at least {} characters long",
+                self.min_length.unwrap()
+            ));
+        }
+
+        if self.max_length.is_some_and(|max| len > max) {
+            return Err(format!(
+                "must be at most {} characters long",
+                self.max_length.unwrap()
+            ));
+        }
+
+        // values that aren't numbers are reported by the parser
+        if let Ok(number) = arg.parse::<f64>() {
+            if self.min_value.is_some_and(|min| number < min) {
+                return Err(format!("must be at least {}", self.min_value.unwrap()));
+            }
+
+            if self.max_value.is_some_and(|max| number > max) {
+                return Err(format!("must be at most {}", self.max_value.unwrap()));
+            }
+        }
+
+        Ok(arg)
+    }
+}
+
+/// Per-locale names and descriptions, keyed by discord locale (e.g. `de`, `es-ES`).
+#[derive(Debug, Clone, Default)]
+pub struct Localizations {
+    pub names: HashMap<String, String>,
+    pub descriptions: HashMap<String, String>,
+}
+
 #[derive(Debug, Clone)]
 pub struct SlashCommand {
     pub name: String,
     pub description: String,
+    pub localizations: Localizations,
+    pub nsfw: bool,
+    /// Where the command can be used, empty for discord's default.
+    pub contexts: Vec<InteractionContextType>,
+    /// Where the command can be installed, empty for guild installs only.
+    pub integration_types: Vec<IntegrationType>,
     pub fn_sig: Vec<ParamType>,
     pub handler_fn: SlashHandlerFn,
     pub fn_param_names: Vec<String>,
@@ -24,18 +124,127 @@ pub struct SlashCommand {
     pub permissions: Vec<String>,
     pub fn_param_renames: Vec<Option<String>>,
     pub fn_param_autocomplete: Vec<Option<AutoCompleteFn>>,
+    pub fn_param_constraints: Vec<OptionConstraints>,
+    pub fn_param_localizations: Vec<Localizations>,
+    pub cooldown: Option<Cooldown>,
+    /// Overrides the client's handler timeout for this command.
+    pub timeout: Option<std::time::Duration>,
+    pub checks: Checks,
 }
 
 impl SlashCommand {
+    /// Returns the name the parameter at `idx` is registered under.
+    pub fn option_name(&self, idx: usize) -> &str {
+        self.fn_param_renames[idx]
+            .as_deref()
+            .unwrap_or(&self.fn_param_names[idx])
+    }
+
+    /// Runs the autocomplete handler of the focused option.
+    ///
+    /// Returns no choices if the option doesn't have an autocomplete handler.
+    pub async fn autocomplete(&self, data: Interaction) -> Vec<InteractionAutoCompleteChoice> {
+        let options = data
+            .data
+            .as_ref()
+            .and_then(|data| data.options.clone())
+            .unwrap_or_default();
+
+        let Some(focused) = options
+            .iter()
+            .find(|option| option.focused.unwrap_or_default())
+        else {
+            log::warn!("Received an autocomplete request without a focused option");
+            return vec![];
+        };
+
+        let handler = (0..self.fn_sig.len())
+            .find(|&idx| self.option_name(idx) == focused.name)
+            .and_then(|idx| self.fn_param_autocomplete[idx]);
+
+        let Some(handler) = handler else {
+            log::warn!(
+                "No autocomplete handler for option '{}' of '{}' slash command",
+                focused.name,
+                self.name
+            );
+
+            return vec![];
+        };
+
+        let option_type = focused.type_;
+        let context = AutoCompleteContext {
+            focused: focused.name.clone(),
+            value: focused.value.clone(),
+            options: options
+                .iter()
+                .map(|option| (option.name.clone(), option.value.clone()))
+                .collect(),
+            interaction: data,
+        };
+
+        handler(context)
+            .await
+            .into_iter()
+            .filter_map(|choice| {
+                Some(InteractionAutoCompleteChoice {
+                    value: choice.value.coerce(option_type)?,
+                    name: choice.name,
+                })
+            })
+            .take(25)
+            .collect()
+    }
+
     pub async fn call(&self, data: Interaction) -> DescordResult {
-        let split: Vec<String> = data
+        if !has_middleware() {
+            return self.invoke(data).await;
+        }
+
+        let invocation = self.invocation(&data);
+        let command = self.clone();
+        run_middleware(
+            invocation,
+            Box::pin(async move { command.invoke(data).await }),
+        )
+        .await
+    }
+
+    /// Describes a call to the command, for middleware and error reporting.
+    pub(crate) fn invocation(&self, data: &Interaction) -> Invocation {
+        Invocation::SlashCommand {
+            name: self.name.clone(),
+            interaction: data.clone(),
+        }
+    }
+
+    async fn invoke(&self, data: Interaction) -> DescordResult {
+        self.checks.run(&data.clone().into()).await?;
+
+        if let Some(cooldown) = &self.cooldown {
+            let user = data
+                .user
+                .as_ref()
+                .or_else(|| data.member.as_ref().and_then(|member| member.user.as_ref()));
+
+            cooldown
+                .check(
+                    user.map(|user| user.id.as_str()),
+                    data.channel_id.as_deref(),
+                    data.guild_id.as_deref(),
+                )
+                .map_err(Cooldown::error)?;
+        }
+
+        // options are only sent when filled in, in no particular order
+        let options: HashMap<String, String> = data
             .clone()
             .data
             .unwrap_or(InteractionData::default())
             .options
             .unwrap_or_default()
             .iter()
-            .map(|i| i.value.clone())
+            .map(|i| (i.name.clone(), i.value.clone()))
             .collect();
         let mut args: Vec<Value> = Vec::with_capacity(self.fn_sig.len());
 
@@ -43,25 +252,30 @@ impl SlashCommand {
         while idx < self.fn_sig.len() {
             let ty = &self.fn_sig[idx];
             let optional = self.optional_params[idx];
-            if idx < split.len() {
+            if let Some(value) = options.get(self.option_name(idx)) {
                 match ty {
                     ParamType::String => args.push(if optional {
-                        Value::StringOption(Some(split[idx].to_owned()))
+                        Value::StringOption(Some(value.to_owned()))
                     } else {
-                        Value::String(split[idx].to_owned())
+                        Value::String(value.to_owned())
                     }),
                     ParamType::Int => args.push(if optional {
-                        Value::IntOption(Some(split[idx].parse::<isize>().unwrap()))
+                        Value::IntOption(Some(value.parse::<isize>().unwrap()))
+                    } else {
+                        Value::Int(value.parse::<isize>().unwrap())
+                    }),
+                    ParamType::Number => args.push(if optional {
+                        Value::NumberOption(Some(value.parse::<f64>().unwrap()))
                     } else {
-                        Value::Int(split[idx].parse::<isize>().unwrap())
+                        Value::Number(value.parse::<f64>().unwrap())
                     }),
                     ParamType::Bool => args.push(if optional {
-                        Value::BoolOption(Some(split[idx].parse::<bool>().unwrap()))
+                        Value::BoolOption(Some(value.parse::<bool>().unwrap()))
                     } else {
-                        Value::Bool(split[idx].parse::<bool>().unwrap())
+                        Value::Bool(value.parse::<bool>().unwrap())
                     }),
                     ParamType::Channel => {
-                        let channel_id_str = &split[idx];
+                        let channel_id_str = value;
                         let channel_id =
                             if channel_id_str.starts_with("<#") && channel_id_str.ends_with(">") {
                                 &channel_id_str[2..channel_id_str.len() - 1]
@@ -83,7 +297,7 @@ impl SlashCommand {
                         }
                     }
                     ParamType::User => {
-                        let user_id_str = &split[idx];
+                        let user_id_str = value;
                         let user_id = if user_id_str.starts_with("<@") && user_id_str.ends_with(">")
                         {
                             &user_id_str[2..user_id_str.len() - 1]
@@ -110,15 +324,17 @@ impl SlashCommand {
                 match ty {
                     ParamType::String => args.push(Value::StringOption(None)),
                     ParamType::Int => args.push(Value::IntOption(None)),
+                    ParamType::Number => args.push(Value::NumberOption(None)),
                     ParamType::Bool => args.push(Value::BoolOption(None)),
                     ParamType::Channel => args.push(Value::ChannelOption(None)),
                     ParamType::User => args.push(Value::UserOption(None)),
                     _ => {}
                 }
             } else {
-                return Err(Box::new(DescordError::MissingRequiredArgument(
-                    self.name.clone(),
-                )));
+                return Err(Box::new(DescordError::MissingRequiredArgument {
+                    name: self.fn_param_names[idx].clone(),
+                    usage: format!("/{}", self.name),
+                }));
             }
 
             idx += 1;
@@ -1,7 +1,7 @@
 //! Descord is a minimal and easy to use discord api wrapper.
 //!
 //! # Example
-//! ```rust
+//! ```rust,no_run
 //! use descord::prelude::*;
 
 //! #[tokio::main]
@@ -83,7 +83,7 @@
 //!         .image(avatar_url, None, None)
 //!         .build();
 //!
-//!     interaction.reply(embed, false).await;
+//!     interaction.reply(embed, false).await?;
 //! }
 //! ```
 
@@ -91,6 +91,8 @@
 
 mod client;
 mod ws;
+#[cfg(feature = "http-interactions")]
+mod http;
 mod consts;
 
 pub mod models;
@@ -108,7 +110,7 @@ pub use consts::events::Event;
 pub use consts::color;
 
 // TODO: change the error type
-pub type DescordResult = Result<(), Box<dyn std::error::Error + Send>>;
+pub type DescordResult = Result<(), Box<dyn std::error::Error + Send + Sync>>;
 
 pub(crate) mod cache;
 
@@ -119,12 +121,20 @@ pub mod prelude {
         consts::ButtonStyle,
         consts::ComponentType,
         consts::ImageFormat,
+        consts::InteractionContextType,
+        consts::IntegrationType,
         consts::SelectMenuType,
         consts::intents::GatewayIntent,
         consts::color::Color,
+        internals::AutoCompleteContext,
+        internals::{
+            format_duration, ArgumentFuture, CommandArgs, Cooldown, CooldownBucket, ErrorContext,
+            FromArgument, Invocation, Next,
+        },
+        utils::ComponentState,
         models::{
             channel::*, channel::*, component_builder::*, components::*, embed::*,
-            embed_builder::*, guild::*, interaction::*, message_response::CreateMessageData,
+            embed_builder::*, guild::*, hybrid_context::HybridContext, interaction::*, message_response::CreateMessageData,
             message_response::Message, reaction_response::Reaction, ready_response::*, role::Role,
             role_response::*, user::User,
         },
@@ -1,5 +1,6 @@
 use descord::prelude::*;
 use models::attachment::AttachmentPayload;
+use nanoserde::{DeBin, SerBin};
 
 #[tokio::main]
 async fn main() {
@@ -13,6 +14,50 @@ async fn main() {
     )
     .await;
 
+    // `!PING` and `@Bot ping` work too
+    client.case_insensitive_commands(true);
+
+    // `!pnig` replies with "did you mean `!ping`?", at most once every 10 seconds per user
+    client.suggest_commands(Cooldown::parse("10s", CooldownBucket::User, 1));
+
+    // fixing a typo in a command by editing it within a minute runs it again
+    client.rerun_on_edit(Some(std::time::Duration::from_secs(60)));
+
+    // `?` works as well in DMs
+    client.prefix_resolver(|msg: Message| async move {
+        match msg.guild_id {
+            Some(_) => vec!["!"],
+            None => vec!["!", "?"],
+        }
+    });
+
+    // signs the state carried in component custom ids
+    client.set_component_secret("a long random secret");
+
+    // hung handlers are cancelled and reported through `on_error`
+    client.handler_timeout(Some(std::time::Duration::from_secs(60)));
+
+    // log how long every command takes
+    client.around(|invocation, next| async move {
+        let start = std::time::Instant::now();
+        let result = next.run().await;
+
+        if !matches!(invocation, Invocation::Event { .. }) {
+            log::info!("{} took {:?}", invocation.name(), start.elapsed());
+        }
+
+        result
+    });
+
+    client.on_error(|ctx| async move {
+        // stay quiet about cooldowns
+        if let Some(internals::DescordError::OnCooldown { .. }) = ctx.descord_error() {
+            return;
+        }
+
+        ctx.report().await;
+    });
+
     register_all!(client => []);
 
     client.login().await;
@@ -41,7 +86,7 @@ async fn send_attach(msg: Message) {
     .await;
 }
 
-#[command(description = "A command which will invoke an internal error")]
+#[command(owner_only, description = "A command which will invoke an internal error")]
 async fn test(msg: Message) {
     msg.send_in_channel("").await;
 }
@@ -67,11 +112,11 @@ async fn ping(
                 format!("Hello, {}! You are in {}", user.mention, channel.mention),
                 false,
             )
-            .await;
+            .await?;
     } else {
         interaction
             .reply(format!("You are in {}", channel.mention), false)
-            .await;
+            .await?;
     }
 }
 
@@ -81,8 +126,8 @@ async fn ping(
     permissions = "administrator"
 )]
 async fn echo_slash(interaction: Interaction, #[autocomplete = auto_cmp] message: String) {
-    interaction.defer().await;
-    interaction.followup(message).await;
+    interaction.defer().await?;
+    interaction.followup(message, false).await?;
 }
 
 async fn auto_cmp(value: String) -> Vec<String> {
@@ -94,11 +139,66 @@ async fn auto_cmp(value: String) -> Vec<String> {
         .collect()
 }
 
-#[slash(name = "whisper", description = "Respond with ephemeral message")]
+#[slash(name = "mute", description = "Mute a user for some time")]
+async fn mute(
+    interaction: Interaction,
+    /// User to mute
+    user: User,
+    /// How long to mute them for
+    #[autocomplete = mute_durations]
+    seconds: isize,
+) {
+    interaction
+        .reply(format!("Muted {} for {seconds}s", user.username), false)
+        .await?;
+}
+
+async fn mute_durations(ctx: AutoCompleteContext) -> Vec<(String, isize)> {
+    let durations = [("1 minute", 60), ("1 hour", 3600), ("1 day", 86400)];
+    let suffix = if ctx.options.contains_key("user") {
+        ""
+    } else {
+        " (pick a user first)"
+    };
+
+    durations
+        .into_iter()
+        .filter(|(name, _)| name.contains(&ctx.value))
+        .map(|(name, seconds)| (format!("{name}{suffix}"), seconds))
+        .collect()
+}
+
+#[slash(
+    name = "roll",
+    description = "Roll a die",
+    localize(locale = "de", name = "wuerfeln", description = "Einen Würfel werfen")
+)]
+async fn roll(
+    interaction: Interaction,
+    /// Number of sides
+    #[localize(locale = "de", name = "seiten", description = "Anzahl der Seiten")]
+    #[choice = 6]
+    #[choice(name = "Twenty", value = 20)]
+    sides: isize,
+    /// Why are you rolling
+    #[max_length = 100]
+    reason: Option<String>,
+) {
+    interaction
+        .reply(format!("Rolling a d{sides}, reason: {reason:?}"), false)
+        .await?;
+}
+
+#[slash(
+    name = "whisper",
+    description = "Respond with ephemeral message",
+    contexts = [guild, bot_dm, private_channel],
+    integration_types = [guild_install, user_install]
+)]
 async fn whisper(interaction: Interaction) {
     interaction
         .reply("This is an ephemeral message", true)
-        .await;
+        .await?;
 }
 
 // without cache info
@@ -138,10 +238,16 @@ async fn avatar(interaction: Interaction, #[doc = "User to fetch avatar from"] u
         .image(avatar, None, None)
         .build();
 
-    interaction.reply(embed, false).await;
+    interaction.reply(embed, false).await?;
 }
 
-#[command(description = "Count up or down")]
+#[command(
+    aliases = ["count", "c"],
+    category = "Fun",
+    cooldown = "10s",
+    bucket = "channel",
+    description = "Count up or down"
+)]
 async fn counter(msg: Message) {
     let msg = msg.send_in_channel("Count: 0").await;
 
@@ -152,7 +258,7 @@ async fn counter(msg: Message) {
 #[command(description = "React to the message with the given emoji")]
 async fn react(msg: Message, emoji: String) {
     println!("reacting");
-    msg.react(emoji).await;
+    msg.react(&emoji).await;
 }
 
 #[event]
@@ -193,20 +299,140 @@ async fn reaction_add(reaction: Reaction) {
     }
 }
 
-#[command]
-async fn kick(msg: Message, user: User) {
-    msg.reply("kicking").await;
-    utils::kick_member(msg.guild_id.as_ref().unwrap(), &user.id, None).await.unwrap();
+// !kick @user --reason "spam links" --silent
+#[command(
+    guild_only,
+    category = "Moderation",
+    permissions = "kick_members",
+    bot_permissions = "kick_members",
+    checks = [not_self]
+)]
+async fn kick(msg: Message, user: User, #[named] reason: Option<String>, #[flag] silent: bool) {
+    if !silent {
+        msg.reply("kicking").await;
+    }
+
+    utils::kick_member(msg.guild_id.as_ref().unwrap(), &user.id, reason).await.unwrap();
+}
+
+// Custom checks can return `bool` or `Result<(), E>` to explain why they failed
+#[check]
+async fn not_self(ctx: HybridContext) -> Result<(), String> {
+    let mentions_self = ctx
+        .message()
+        .zip(ctx.user())
+        .is_some_and(|(msg, user)| msg.content.contains(&user.id));
+
+    match mentions_self {
+        true => Err("You can't use this on yourself".to_string()),
+        false => Ok(()),
+    }
+}
+
+#[derive(CommandArgs)]
+struct WarnArgs {
+    /// The user to warn
+    user: User,
+    /// Why they are warned
+    #[max_length = 200]
+    reason: Option<String>,
+    /// Don't mention the user
+    #[flag]
+    silent: bool,
+}
+
+// Both `!warn @user spam --silent` and `/warn`
+#[command(category = "Moderation", description = "Warn a user")]
+async fn warn(msg: Message, #[args] args: WarnArgs) {
+    msg.reply(warning(args)).await;
+}
+
+#[slash(name = "warn", description = "Warn a user")]
+async fn warn_slash(interaction: Interaction, #[args] args: WarnArgs) {
+    interaction.reply(warning(args), false).await?;
+}
+
+fn warning(args: WarnArgs) -> String {
+    let user = if args.silent {
+        args.user.username
+    } else {
+        args.user.mention
+    };
+
+    match args.reason {
+        Some(reason) => format!("{user} has been warned: {reason}"),
+        None => format!("{user} has been warned"),
+    }
+}
+
+// Registered as both `!whoami` and `/whoami`
+#[hybrid(cooldown = "30s", burst = 3, description = "Show who invoked the command")]
+async fn whoami(ctx: HybridContext, #[doc = "Only visible to you"] hidden: Option<bool>) {
+    let name = ctx.user().map_or("someone", |user| user.username.as_str());
+    let origin = if ctx.message().is_some() { "a message" } else { "a slash command" };
+
+    ctx.reply(format!("You are {name}, using {origin}"), hidden.unwrap_or(false))
+        .await?;
 }
 
 #[component(id = "btn1")]
 async fn btn1(int: Interaction) {
-    int.reply("You clicked me!", false).await;
+    int.reply("You clicked me!", false).await?;
 }
 
 #[component(id = "btn2")]
 async fn btn2(int: Interaction) {
-    int.reply("I told you not to click me!", false).await;
+    int.reply("I told you not to click me!", false).await?;
+}
+
+#[component(id = "ticket:{ticket_id}:close", guild_only)]
+async fn close_ticket(int: Interaction, ticket_id: isize) {
+    int.reply(format!("Closed ticket #{ticket_id}"), false).await?;
+}
+
+#[component(fallback)]
+async fn unknown_component(int: Interaction) {
+    int.reply("This button doesn't do anything anymore", true).await?;
+}
+
+#[command(description = "Open a ticket")]
+async fn ticket(message: Message) {
+    let close = ComponentBuilder::button(ButtonObject {
+        style: ButtonStyle::Danger as _,
+        label: Some("Close".to_string()),
+        custom_id: Some(format!("ticket:{}:close", message.id)),
+        ..Default::default()
+    })
+    .unwrap();
+
+    message.reply(vec![vec![close]]).await;
+}
+
+#[derive(SerBin, DeBin)]
+struct Tally {
+    count: u32,
+}
+
+#[component(id = "tally")]
+async fn tally_click(int: Interaction, #[state] tally: Tally) {
+    int.update_message(tally_button(tally.count + 1)).await?;
+}
+
+#[command(description = "Send a button that counts its clicks")]
+async fn tally(message: Message) {
+    message.reply(tally_button(0)).await;
+}
+
+fn tally_button(count: u32) -> Vec<Vec<Component>> {
+    let button = ComponentBuilder::button(ButtonObject {
+        style: ButtonStyle::Primary as _,
+        label: Some(format!("Clicked {count} times")),
+        custom_id: Some(Tally { count }.custom_id("tally").unwrap()),
+        ..Default::default()
+    })
+    .unwrap();
+
+    vec![vec![button]]
 }
 
 #[command(description = "Send a message with components")]
@@ -256,25 +482,44 @@ async fn components(message: Message) {
     })
     .unwrap();
 
+    let role_select = ComponentBuilder::select(SelectObject {
+        select_type: SelectMenuType::RoleSelect,
+        placeholder: Some(String::from("Role Select example")),
+        custom_id: String::from("roles"),
+        max_values: Some(3),
+        ..Default::default()
+    })
+    .unwrap();
+
     // Column<Row<>>
     message
-        .reply(vec![vec![b1], vec![b2, b3], vec![select]])
+        .reply(vec![
+            vec![b1],
+            vec![b2, b3],
+            vec![select],
+            vec![role_select],
+        ])
         .await;
 }
 
-#[command(description = "Replies with a message after a specified delay (in seconds)")]
-async fn delay(msg: Message) {
-    let delay = msg
-        .content
-        .split_once(' ')
-        .unwrap()
-        .1
-        .parse::<u64>()
-        .unwrap();
+#[component(id = "select")]
+async fn select(int: Interaction, values: Vec<String>) {
+    int.reply(format!("You picked {}", values.join(", ")), true)
+        .await?;
+}
+
+#[component(id = "roles")]
+async fn pick_roles(int: Interaction, roles: Vec<Role>) {
+    let names: Vec<_> = roles.iter().map(|role| role.name.as_str()).collect();
+    int.reply(format!("You picked {}", names.join(", ")), true)
+        .await?;
+}
 
+#[command(description = "Replies with a message after a specified delay")]
+async fn delay(msg: Message, delay: std::time::Duration) {
     msg.get_channel().await.unwrap().send_typing().await;
 
-    tokio::time::sleep(tokio::time::Duration::from_secs(delay)).await;
+    tokio::time::sleep(delay).await;
     msg.reply("The quick brown fox jumps over the lazy dog!")
         .await;
 }
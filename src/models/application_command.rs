@@ -16,10 +16,12 @@ pub struct ApplicationCommand {
     pub guild_id: Option<String>,
     /// The name of the command.
     pub name: String,
-    // name_localizations: Option<>,
+    /// Localized names for the command.
+    pub name_localizations: Option<HashMap<String, String>>,
     /// The description of the command.
     pub description: String,
-    // description_localizations: Option<>,
+    /// Localized descriptions for the command.
+    pub description_localizations: Option<HashMap<String, String>>,
     /// The options for the command.
     pub options: Option<Vec<ApplicationCommandOption>>,
     /// The default member permissions required to use the command.
@@ -27,7 +29,7 @@ pub struct ApplicationCommand {
     /// Whether the command is marked as NSFW.
     pub nsfw: Option<bool>,
     /// The integration types for the command.
-    pub integration_types: Vec<u32>,
+    pub integration_types: Option<Vec<u32>>,
     /// The contexts in which the command can be used.
     pub contexts: Option<Vec<u32>>,
     /// The version of the command.
@@ -57,9 +59,9 @@ pub struct ApplicationCommandOption {
     /// The channel types for the option.
     pub channel_types: Option<Vec<u32>>,
     /// The minimum value for the option.
-    pub min_value: Option<i32>,
+    pub min_value: Option<f64>,
     /// The maximum value for the option.
-    pub max_value: Option<i32>,
+    pub max_value: Option<f64>,
     /// The minimum length for the option.
     pub min_length: Option<i32>,
     /// The maximum length for the option.
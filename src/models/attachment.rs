@@ -55,7 +55,7 @@ impl AttachmentPayload {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let payload = AttachmentPayload::new("file.txt", "/path/to/file.txt", "text/plain");
     /// ```
     pub fn new(file_name: &str, file_path: &str, mime_type: &str) -> Self {
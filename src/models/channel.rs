@@ -101,7 +101,7 @@ impl Channel {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// channel.send_message("Hello, world!").await;
     /// ```
     pub async fn send_message(&self, data: impl Into<CreateMessageData>) -> Message {
@@ -112,7 +112,7 @@ impl Channel {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// channel.send_typing().await;
     /// ```
     pub async fn send_typing(&self) {
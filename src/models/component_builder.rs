@@ -17,7 +17,7 @@ impl ComponentBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let button = ButtonObject {
     ///     style: ButtonStyle::Primary,
     ///     label: Some("Click me".to_string()),
@@ -67,7 +67,7 @@ impl ComponentBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let select = SelectObject {
     ///     select_type: 3,
     ///     custom_id: "select1".to_string(),
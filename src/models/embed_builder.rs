@@ -12,7 +12,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let builder = EmbedBuilder::new();
     /// ```
     pub fn new() -> Self {
@@ -25,7 +25,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let embed = EmbedBuilder::new().title("Title").build();
     /// ```
     pub fn build(self) -> embed::Embed {
@@ -40,7 +40,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let builder = EmbedBuilder::new().title("Title");
     /// ```
     pub fn title(mut self, title: &str) -> Self {
@@ -56,7 +56,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let builder = EmbedBuilder::new().description("Description");
     /// ```
     pub fn description(mut self, description: &str) -> Self {
@@ -72,7 +72,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let builder = EmbedBuilder::new().color(Color::from_rgb(255, 0, 0));
     /// ```
     pub fn color(mut self, color: Color) -> Self {
@@ -90,7 +90,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let builder = EmbedBuilder::new().footer("Footer text", Some("https://example.com/icon.png".to_string()), None);
     /// ```
     pub fn footer(
@@ -118,7 +118,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let builder = EmbedBuilder::new().image("https://example.com/image.png".to_string(), Some(100), Some(100));
     /// ```
     pub fn image(mut self, url: String, height: Option<u32>, width: Option<u32>) -> Self {
@@ -140,7 +140,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let thumbnail = embed::EmbedThumbnail {
     ///     url: "https://example.com/thumbnail.png".to_string(),
     ///     proxy_url: None,
@@ -162,7 +162,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let video = embed::EmbedVideo {
     ///     url: "https://example.com/video.mp4".to_string(),
     ///     proxy_url: None,
@@ -184,7 +184,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let author = embed::EmbedAuthor {
     ///     name: "Author".to_string(),
     ///     url: Some("https://example.com".to_string()),
@@ -212,7 +212,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let builder = EmbedBuilder::new().field("Field name", "Field value", true);
     /// ```
     pub fn field(mut self, name: &str, value: &str, inline: bool) -> Self {
@@ -241,7 +241,7 @@ impl EmbedBuilder {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let fields = vec![
     ///     embed::EmbedField {
     ///         name: "Field 1".to_string(),
@@ -30,7 +30,7 @@ impl Emoji {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let emoji = Emoji::parse(":star:");
     /// ```
     pub fn parse(emoji: &str) -> Self {
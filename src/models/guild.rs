@@ -250,7 +250,7 @@ impl Guild {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let member = guild.fetch_member("user_id").await?;
     /// ```
     pub async fn fetch_member(&self, user_id: &str) -> Result<Member, Box<dyn std::error::Error>> {
@@ -265,7 +265,7 @@ impl Guild {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let role = guild.fetch_role("role_id").await?;
     /// ```
     pub async fn fetch_role(&self, role_id: &str) -> Result<Role, Box<dyn std::error::Error>> {
@@ -276,7 +276,7 @@ impl Guild {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let default_role = guild.default_role().await?;
     /// ```
     pub async fn default_role(&self) -> Result<Role, Box<dyn std::error::Error>> {
@@ -294,7 +294,7 @@ impl Member {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let avatar_url = member.get_avatar_url(ImageFormat::PNG, Some(128));
     /// ```
     pub fn get_avatar_url(&self, image_format: ImageFormat, size: Option<u32>) -> Option<String> {
@@ -325,7 +325,7 @@ impl Member {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// member.send_dm("Hello, world!").await;
     /// ```
     pub async fn send_dm(&self, data: impl Into<CreateMessageData>) {
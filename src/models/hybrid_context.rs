@@ -0,0 +1,128 @@
+use super::guild::Member;
+use super::interaction::Interaction;
+use super::message_response::{CreateMessageData, Message};
+use super::user::User;
+use crate::internals::DescordError;
+
+/// What a hybrid command was invoked with, a message or a slash command.
+///
+/// See `#[descord::hybrid]`.
+#[derive(Debug, Clone)]
+pub enum HybridContext {
+    Message(Box<Message>),
+    Interaction(Box<Interaction>),
+}
+
+impl From<Message> for HybridContext {
+    fn from(value: Message) -> Self {
+        HybridContext::Message(Box::new(value))
+    }
+}
+
+impl From<Interaction> for HybridContext {
+    fn from(value: Interaction) -> Self {
+        HybridContext::Interaction(Box::new(value))
+    }
+}
+
+impl HybridContext {
+    /// Replies to the command.
+    ///
+    /// Replies to messages can't be ephemeral, `ephemeral` only applies to slash commands.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The response data.
+    /// * `ephemeral` - Whether the response should be ephemeral.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// ctx.reply("Hello, world!", false).await?;
+    /// ```
+    pub async fn reply(
+        &self,
+        response: impl Into<CreateMessageData>,
+        ephemeral: bool,
+    ) -> Result<(), DescordError> {
+        match self {
+            HybridContext::Message(message) => {
+                message.reply(response).await;
+                Ok(())
+            }
+
+            HybridContext::Interaction(interaction) => interaction.reply(response, ephemeral).await,
+        }
+    }
+
+    /// Lets the user know the bot is working on a response.
+    ///
+    /// Defers slash commands and shows the typing indicator for messages.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// ctx.defer().await?;
+    /// ```
+    pub async fn defer(&self) -> Result<(), DescordError> {
+        match self {
+            HybridContext::Message(message) => {
+                if let Err(e) = message.send_typing().await {
+                    log::warn!("Failed to send the typing indicator: {e}");
+                }
+
+                Ok(())
+            }
+
+            HybridContext::Interaction(interaction) => interaction.defer().await,
+        }
+    }
+
+    /// Returns the user who invoked the command.
+    pub fn user(&self) -> Option<&User> {
+        match self {
+            HybridContext::Message(message) => message.author.as_ref(),
+            HybridContext::Interaction(interaction) => interaction.author(),
+        }
+    }
+
+    /// Returns the member who invoked the command, `None` outside of guilds.
+    pub fn member(&self) -> Option<&Member> {
+        match self {
+            HybridContext::Message(message) => message.member.as_ref(),
+            HybridContext::Interaction(interaction) => interaction.member.as_ref(),
+        }
+    }
+
+    /// Returns the id of the channel the command was invoked in.
+    pub fn channel_id(&self) -> Option<&str> {
+        match self {
+            HybridContext::Message(message) => Some(&message.channel_id),
+            HybridContext::Interaction(interaction) => interaction.channel_id.as_deref(),
+        }
+    }
+
+    /// Returns the id of the guild the command was invoked in.
+    pub fn guild_id(&self) -> Option<&str> {
+        match self {
+            HybridContext::Message(message) => message.guild_id.as_deref(),
+            HybridContext::Interaction(interaction) => interaction.guild_id.as_deref(),
+        }
+    }
+
+    /// Returns the message if the command was invoked with a message.
+    pub fn message(&self) -> Option<&Message> {
+        match self {
+            HybridContext::Message(message) => Some(message),
+            HybridContext::Interaction(_) => None,
+        }
+    }
+
+    /// Returns the interaction if the command was invoked as a slash command.
+    pub fn interaction(&self) -> Option<&Interaction> {
+        match self {
+            HybridContext::Message(_) => None,
+            HybridContext::Interaction(interaction) => Some(interaction),
+        }
+    }
+}
@@ -1,16 +1,21 @@
 use json::JsonValue;
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
+use crate::cache::INTERACTION_RESPONSES;
 use crate::consts::*;
+use crate::internals::DescordError;
 use crate::models::allowed_mentions::AllowedMentions;
 use crate::models::guild::Member;
 use crate::prelude::{Component, Embed};
-use crate::utils::request;
+use crate::utils::{self, request};
 use nanoserde::{DeJson, SerJson};
 use reqwest::Method;
 
 use super::guild::{Guild, PartialGuild};
 use super::message_response::CreateMessageData;
+use super::role::Role;
 use super::{channel::Channel, message_response::Message, user::User};
 
 /// Payload for an interaction response.
@@ -44,22 +49,181 @@ pub struct Interaction {
     /// The message associated with the interaction.
     pub message: Option<Message>,
     /// The application permissions.
+    #[nserde(default)]
     pub app_permissions: String,
     /// The locale of the interaction.
     pub locale: Option<String>,
     /// The guild locale of the interaction.
     pub guild_locale: Option<String>,
-    /// The context of the interaction.
+    /// The context of the interaction, see [`Interaction::context_type`].
     pub context: Option<u32>,
-    /// The guild ID where the interaction was sent.
-    pub guild_id: String,
+    /// The guild ID where the interaction was sent, `None` in DMs.
+    pub guild_id: Option<String>,
     /// The user who invoked the interaction, if in a DM.
     pub user: Option<User>,
+    /// The installations that authorized the interaction, keyed by integration type.
+    ///
+    /// `"0"` maps to the ID of the guild the app is installed to (`"0"` if triggered
+    /// from the DMs with the bot user), `"1"` to the ID of the user who installed the app.
+    pub authorizing_integration_owners: Option<HashMap<String, String>>,
 }
 
 impl Interaction {
+    /// Returns the context the interaction was triggered from.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// if interaction.context_type() == Some(InteractionContextType::Guild) {
+    ///     // ...
+    /// }
+    /// ```
+    pub fn context_type(&self) -> Option<InteractionContextType> {
+        self.context?.try_into().ok()
+    }
+
+    /// Returns the user who invoked the interaction, both in guilds and DMs.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let username = &interaction.author().unwrap().username;
+    /// ```
+    pub fn author(&self) -> Option<&User> {
+        self.member
+            .as_ref()
+            .and_then(|member| member.user.as_ref())
+            .or(self.user.as_ref())
+    }
+
+    /// Returns true if the app was installed by the user rather than the guild
+    /// for this interaction, meaning the bot might not be a member of the guild.
+    pub fn is_user_installed(&self) -> bool {
+        self.authorizing_integration_owners
+            .as_ref()
+            .is_some_and(|owners| !owners.contains_key("0") && owners.contains_key("1"))
+    }
+
+    /// Looks up a translated string in the locale of the user who invoked the interaction.
+    ///
+    /// Falls back to the guild locale and then to the default locale,
+    /// returns the key itself if no translation is found.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The translation key.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// interaction.reply(interaction.t("greet.hello"), false).await;
+    /// ```
+    pub fn t(&self, key: &str) -> String {
+        [self.locale.as_deref(), self.guild_locale.as_deref()]
+            .into_iter()
+            .flatten()
+            .chain([utils::DEFAULT_LOCALE])
+            .find_map(|locale| utils::translate_exact(locale, key))
+            .unwrap_or_else(|| key.to_string())
+    }
+
+    /// Returns the values picked in a string select menu.
+    pub fn selected_values(&self) -> Vec<String> {
+        self.data
+            .as_ref()
+            .and_then(|data| data.values.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns the users picked in a user or mentionable select menu.
+    pub fn selected_users(&self) -> Vec<User> {
+        self.selected(|resolved| resolved.users.as_ref())
+    }
+
+    /// Returns the members picked in a user or mentionable select menu.
+    ///
+    /// Only available in guilds.
+    pub fn selected_members(&self) -> Vec<Member> {
+        let users = self
+            .data
+            .as_ref()
+            .and_then(|data| data.resolved.as_ref()?.users.as_ref());
+
+        self.selected_ids()
+            .filter_map(|id| {
+                let resolved = self.data.as_ref()?.resolved.as_ref()?;
+                let mut member = resolved.members.as_ref()?.get(id)?.clone();
+
+                // resolved members don't include the user object
+                if member.user.is_none() {
+                    member.user = users.and_then(|users| users.get(id).cloned());
+                }
+
+                Some(member)
+            })
+            .collect()
+    }
+
+    /// Returns the roles picked in a role or mentionable select menu.
+    pub fn selected_roles(&self) -> Vec<Role> {
+        self.selected(|resolved| resolved.roles.as_ref())
+    }
+
+    /// Returns the channels picked in a channel select menu.
+    pub fn selected_channels(&self) -> Vec<Channel> {
+        self.selected(|resolved| resolved.channels.as_ref())
+    }
+
+    /// Looks up the picked values in the resolved data, keeping the order they were picked in.
+    fn selected<T: Clone>(
+        &self,
+        map: impl Fn(&ResolvedData) -> Option<&HashMap<String, T>>,
+    ) -> Vec<T> {
+        let resolved = self
+            .data
+            .as_ref()
+            .and_then(|data| map(data.resolved.as_ref()?));
+
+        let Some(resolved) = resolved else {
+            return vec![];
+        };
+
+        self.selected_ids()
+            .filter_map(|id| resolved.get(id).cloned())
+            .collect()
+    }
+
+    fn selected_ids(&self) -> impl Iterator<Item = &String> {
+        self.data
+            .iter()
+            .flat_map(|data| data.values.iter().flatten())
+    }
+
+    /// Decodes the state carried in the custom id of the component that was used.
+    ///
+    /// See [`ComponentState`](crate::utils::ComponentState).
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let vote: Vote = interaction.state()?;
+    /// ```
+    pub fn state<T: nanoserde::DeBin>(&self) -> Result<T, crate::internals::DescordError> {
+        let custom_id = self
+            .data
+            .as_ref()
+            .and_then(|data| data.custom_id.as_deref())
+            .unwrap_or_default();
+
+        utils::decode_state(custom_id)
+    }
+
     /// Sends a reply to the interaction.
     ///
+    /// If the interaction was deferred the deferred response is edited,
+    /// if it was already replied to a follow-up message is sent instead.
+    /// Attachments are uploaded along with the reply.
+    ///
     /// # Arguments
     ///
     /// * `response` - The response data.
@@ -67,122 +231,406 @@ impl Interaction {
     ///
     /// # Examples
     ///
+    /// ```ignore
+    /// interaction.reply("Hello, world!", true).await?;
     /// ```
-    /// interaction.reply("Hello, world!", true).await;
-    /// ```
-    pub async fn reply(&self, response: impl Into<CreateMessageData>, ephemeral: bool) {
+    pub async fn reply(
+        &self,
+        response: impl Into<CreateMessageData>,
+        ephemeral: bool,
+    ) -> Result<(), DescordError> {
         let mut message_data: CreateMessageData = response.into();
         ephemeral.then(|| message_data.flags = Some(64));
 
-        let response = InteractionResponse {
-            type_: 4,
-            data: Some(message_data),
-        };
-        let json_response = SerJson::serialize_json(&response);
+        let state = self.response_state_lock();
+        let mut state = state.lock().await;
+
+        match *state {
+            ResponseState::Pending => {
+                self.respond(
+                    InteractionCallbackType::ChannelMessageWithSource,
+                    Some(message_data),
+                )
+                .await?;
+            }
 
-        request(
-            Method::POST,
-            format!("interactions/{}/{}/callback", self.id, self.token),
-            Some(json_response),
+            ResponseState::Deferred => {
+                self.edit_original(message_data).await?;
+            }
+
+            ResponseState::DeferredUpdate | ResponseState::Replied => {
+                self.followup(message_data, ephemeral).await?;
+            }
+        }
+
+        *state = ResponseState::Replied;
+        Ok(())
+    }
+
+    /// Edits the message the component is attached to, as the response to the interaction.
+    ///
+    /// Only available for component interactions, edits the original
+    /// response if the interaction was already acknowledged.
+    /// Attachments are uploaded along with the update.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The new message data.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// interaction.update_message("Button clicked!").await?;
+    /// ```
+    pub async fn update_message(
+        &self,
+        response: impl Into<CreateMessageData>,
+    ) -> Result<(), DescordError> {
+        let state = self.response_state_lock();
+        let mut state = state.lock().await;
+
+        if *state == ResponseState::Pending {
+            self.respond(
+                InteractionCallbackType::UpdateMessage,
+                Some(response.into()),
+            )
+            .await?;
+
+            *state = ResponseState::Replied;
+        } else {
+            self.edit_original(response).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Defers the interaction response, showing a loading state to the user.
+    ///
+    /// Use [`Interaction::reply`] or [`Interaction::followup`] to respond later.
+    /// Does nothing if the interaction was already acknowledged.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// interaction.defer().await?;
+    /// ```
+    pub async fn defer(&self) -> Result<(), DescordError> {
+        self.acknowledge(
+            InteractionCallbackType::DeferredChannelMessageWithSource,
+            None,
+            ResponseState::Deferred,
         )
-        .await;
+        .await
     }
 
-    /// Defers the interaction response.
+    /// Defers the interaction response, the response will be ephemeral.
+    ///
+    /// Does nothing if the interaction was already acknowledged.
     ///
     /// # Examples
     ///
+    /// ```ignore
+    /// interaction.defer_ephemeral().await?;
     /// ```
-    /// interaction.defer().await;
+    pub async fn defer_ephemeral(&self) -> Result<(), DescordError> {
+        self.acknowledge(
+            InteractionCallbackType::DeferredChannelMessageWithSource,
+            Some(CreateMessageData {
+                flags: Some(64),
+                ..Default::default()
+            }),
+            ResponseState::Deferred,
+        )
+        .await
+    }
+
+    /// Acknowledges a component interaction without showing a loading state.
+    ///
+    /// Use [`Interaction::update_message`] to edit the message later.
+    /// Does nothing if the interaction was already acknowledged.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// interaction.defer_update().await?;
     /// ```
-    pub async fn defer(&self) {
-        let response = InteractionResponse {
-            type_: 5,
-            data: None,
+    pub async fn defer_update(&self) -> Result<(), DescordError> {
+        self.acknowledge(
+            InteractionCallbackType::DeferredUpdateMessage,
+            None,
+            ResponseState::DeferredUpdate,
+        )
+        .await
+    }
+
+    /// Returns how the interaction has been responded to so far.
+    pub async fn response_state(&self) -> ResponseState {
+        *self.response_state_lock().lock().await
+    }
+
+    /// Returns how long ago the interaction was created.
+    pub fn age(&self) -> Option<Duration> {
+        let id = self.id.parse::<u64>().ok()?;
+        let created_at = UNIX_EPOCH + Duration::from_millis((id >> 22) + DISCORD_EPOCH);
+
+        SystemTime::now().duration_since(created_at).ok()
+    }
+
+    /// Defers the interaction if it wasn't acknowledged yet.
+    ///
+    /// Components are deferred without a loading state, see [`Interaction::defer_update`].
+    pub(crate) async fn auto_defer(&self) {
+        log::info!("Handler for interaction {} is slow, deferring", self.id);
+
+        let deferred = if self.type_ == InteractionType::MessageComponent as u32 {
+            self.defer_update().await
+        } else {
+            self.defer().await
         };
-        let json_response = SerJson::serialize_json(&response);
 
-        request(
-            Method::POST,
-            format!("interactions/{}/{}/callback", self.id, self.token),
-            Some(json_response),
-        )
-        .await;
+        if let Err(e) = deferred {
+            log::error!("Failed to defer interaction {}: {e}", self.id);
+        }
+    }
+
+    /// Sends the initial response if the interaction wasn't acknowledged yet.
+    async fn acknowledge(
+        &self,
+        type_: InteractionCallbackType,
+        data: Option<CreateMessageData>,
+        new_state: ResponseState,
+    ) -> Result<(), DescordError> {
+        let state = self.response_state_lock();
+        let mut state = state.lock().await;
+
+        if *state == ResponseState::Pending {
+            self.respond(type_, data).await?;
+            *state = new_state;
+        }
+
+        Ok(())
+    }
+
+    fn response_state_lock(&self) -> Arc<tokio::sync::Mutex<ResponseState>> {
+        INTERACTION_RESPONSES
+            .lock()
+            .unwrap()
+            .get_or_insert(self.id.clone(), Default::default)
+            .clone()
+    }
+
+    /// Returns an error if the interaction token can't be used anymore.
+    fn check_expired(&self) -> Result<(), DescordError> {
+        if self
+            .age()
+            .is_some_and(|age| age > INTERACTION_TOKEN_LIFETIME)
+        {
+            log::warn!(
+                "Tried to respond to interaction {} but its token expired, tokens are only valid for 15 minutes",
+                self.id
+            );
+
+            return Err(DescordError::InteractionExpired);
+        }
+
+        Ok(())
+    }
+
+    /// Sends an interaction response, using multipart if there are attachments.
+    async fn respond(
+        &self,
+        type_: InteractionCallbackType,
+        data: Option<CreateMessageData>,
+    ) -> Result<(), DescordError> {
+        self.check_expired()?;
+        let endpoint = format!("interactions/{}/{}/callback", self.id, self.token);
+
+        let mut body = json::object! { type: type_ as u32 };
+        let mut attachments = vec![];
+
+        if let Some(data) = data {
+            body["data"] = json::parse(&data.to_json()).unwrap();
+            attachments = data.attachments;
+        }
+
+        // interactions received over HTTP are answered in the HTTP response,
+        // responses with attachments still go through the callback endpoint
+        #[cfg(feature = "http-interactions")]
+        if let Some(sender) = crate::http::take_response_channel(&self.id) {
+            if attachments.is_empty() {
+                let _ = sender.send(body.dump());
+                return Ok(());
+            }
+        }
+
+        utils::request_with_attachments(Method::POST, endpoint, body.dump(), attachments).await?;
+        Ok(())
     }
 
     /// Sends a follow-up message to the interaction.
     ///
+    /// Attachments are uploaded along with the message.
+    ///
     /// # Arguments
     ///
-    /// * `response` - The follow-up message content.
+    /// * `response` - The follow-up message data.
+    /// * `ephemeral` - Whether the follow-up should be ephemeral.
     ///
     /// # Examples
     ///
+    /// ```ignore
+    /// let message = interaction.followup("Follow-up message", false).await?;
     /// ```
-    /// interaction.followup("Follow-up message").await;
+    pub async fn followup(
+        &self,
+        response: impl Into<CreateMessageData>,
+        ephemeral: bool,
+    ) -> Result<Message, DescordError> {
+        let mut message_data: CreateMessageData = response.into();
+        ephemeral.then(|| message_data.flags = Some(64));
+
+        self.webhook_message(Method::POST, "", message_data).await
+    }
+
+    /// Edits a follow-up message.
+    ///
+    /// # Arguments
+    ///
+    /// * `message_id` - The ID of the follow-up message.
+    /// * `response` - The new message data.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let message = interaction.followup("Loading...", false).await?;
+    /// interaction.edit_followup(&message.id, "Done!").await?;
     /// ```
-    pub async fn followup<S: AsRef<str>>(&self, response: S) {
-        request(
-            Method::POST,
-            format!("webhooks/{}/{}", self.application_id, self.token),
-            Some(
-                json::object! {
-                    content: response.as_ref(),
-                }
-                .dump(),
-            ),
+    pub async fn edit_followup(
+        &self,
+        message_id: &str,
+        response: impl Into<CreateMessageData>,
+    ) -> Result<Message, DescordError> {
+        self.webhook_message(
+            Method::PATCH,
+            &format!("/messages/{message_id}"),
+            response.into(),
         )
-        .await;
+        .await
     }
 
-    /// Edits the original interaction response.
+    /// Deletes a follow-up message.
     ///
     /// # Arguments
     ///
-    /// * `response` - The new response data.
+    /// * `message_id` - The ID of the follow-up message.
     ///
     /// # Examples
     ///
+    /// ```ignore
+    /// interaction.delete_followup(&message.id).await?;
     /// ```
-    /// interaction.edit_original("Edited message").await;
-    /// ```
-    pub async fn edit_original(&self, response: impl Into<CreateMessageData>) {
-        let response: CreateMessageData = response.into();
-
-        let resp = request(
-            Method::PATCH,
+    pub async fn delete_followup(&self, message_id: &str) -> Result<(), DescordError> {
+        self.check_expired()?;
+        let response = request(
+            Method::DELETE,
             format!(
-                "webhooks/{}/{}/messages/@original",
+                "webhooks/{}/{}/messages/{message_id}",
                 self.application_id, self.token
             ),
-            Some(response.serialize_json()),
+            None,
         )
         .await;
 
-        println!("{}", resp.text().await.unwrap());
+        utils::check_response(response).await?;
+        Ok(())
     }
 
-    /// Deletes the original interaction response.
+    /// Fetches the original interaction response.
     ///
     /// # Examples
     ///
+    /// ```ignore
+    /// let message = interaction.fetch_original().await?;
     /// ```
-    /// interaction.delete_original().await;
-    /// ```
-    pub async fn delete_original(&self) {
-        request(
-            Method::DELETE,
+    pub async fn fetch_original(&self) -> Result<Message, DescordError> {
+        self.check_expired()?;
+        let response = request(
+            Method::GET,
             format!(
                 "webhooks/{}/{}/messages/@original",
                 self.application_id, self.token
-            )
-            .as_str(),
+            ),
             None,
         )
         .await;
+
+        let body = utils::check_response(response).await?.text().await?;
+        Ok(Message::deserialize_json(&body)?)
+    }
+
+    /// Edits the original interaction response.
+    ///
+    /// # Arguments
+    ///
+    /// * `response` - The new response data.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// interaction.edit_original("Edited message").await?;
+    /// ```
+    pub async fn edit_original(
+        &self,
+        response: impl Into<CreateMessageData>,
+    ) -> Result<Message, DescordError> {
+        self.webhook_message(Method::PATCH, "/messages/@original", response.into())
+            .await
+    }
+
+    /// Deletes the original interaction response.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// interaction.delete_original().await?;
+    /// ```
+    pub async fn delete_original(&self) -> Result<(), DescordError> {
+        self.delete_followup("@original").await
+    }
+
+    /// Sends a message through the interaction webhook and returns the resulting message.
+    async fn webhook_message(
+        &self,
+        method: Method,
+        path: &str,
+        data: CreateMessageData,
+    ) -> Result<Message, DescordError> {
+        self.check_expired()?;
+        let endpoint = format!("webhooks/{}/{}{path}", self.application_id, self.token);
+        let body = data.to_json();
+
+        let response =
+            utils::request_with_attachments(method, endpoint, body, data.attachments).await?;
+        let body = response.text().await?;
+
+        Ok(Message::deserialize_json(&body)?)
     }
 }
 
+/// How an interaction has been responded to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResponseState {
+    /// Not acknowledged yet, discord expects a response within 3 seconds.
+    #[default]
+    Pending,
+    /// Deferred with a loading state, see [`Interaction::defer`].
+    Deferred,
+    /// Acknowledged without a loading state, see [`Interaction::defer_update`].
+    DeferredUpdate,
+    /// Replied to, or the component's message was updated.
+    Replied,
+}
+
 /// Data associated with an interaction.
 #[derive(DeJson, SerJson, Clone, Debug, Default)]
 pub struct InteractionData {
@@ -206,6 +654,8 @@ pub struct InteractionData {
     pub guild_id: Option<String>,
     /// The target ID of the interaction.
     pub target_id: Option<String>,
+    /// The values picked in a select menu.
+    pub values: Option<Vec<String>>,
 }
 
 /// Resolved data associated with an interaction.
@@ -219,7 +669,9 @@ pub struct ResolvedData {
     pub channels: Option<HashMap<String, Channel>>,
     /// The messages involved in the interaction.
     pub messages: Option<HashMap<String, Message>>,
-    // TODO: roles, attachments
+    /// The roles involved in the interaction.
+    pub roles: Option<HashMap<String, Role>>,
+    // TODO: attachments
 }
 
 /// Data for an application command interaction.
@@ -258,7 +710,7 @@ impl InteractionAutoCompleteChoices {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let choices = InteractionAutoCompleteChoices::new(vec![choice1, choice2]);
     /// ```
     pub fn new(choices: Vec<InteractionAutoCompleteChoice>) -> Self {
@@ -279,10 +731,130 @@ pub struct InteractionAutoCompleteChoicePlaceholder {
 /// Represents an autocomplete choice.
 #[derive(DeJson, SerJson, Clone, Debug, Default)]
 pub struct InteractionAutoCompleteChoice {
-    /// The name of the choice.
+    /// The name of the choice, shown to the user.
     pub name: String,
-    /// The value of the choice.
-    pub value: String,
+    /// The value of the choice, sent to the command when picked.
+    pub value: AutoCompleteValue,
+}
+
+impl From<String> for InteractionAutoCompleteChoice {
+    fn from(value: String) -> Self {
+        Self {
+            name: value.clone(),
+            value: value.into(),
+        }
+    }
+}
+
+impl From<&str> for InteractionAutoCompleteChoice {
+    fn from(value: &str) -> Self {
+        value.to_string().into()
+    }
+}
+
+impl<N: Into<String>, V: Into<AutoCompleteValue>> From<(N, V)> for InteractionAutoCompleteChoice {
+    /// Creates a choice with a display name different from its value.
+    ///
+    /// # Examples
+    ///
+    /// ```ignore
+    /// let choice: InteractionAutoCompleteChoice = ("One hour", 3600).into();
+    /// ```
+    fn from((name, value): (N, V)) -> Self {
+        Self {
+            name: name.into(),
+            value: value.into(),
+        }
+    }
+}
+
+/// The value of an autocomplete choice.
+///
+/// Values are converted to the type of the option being autocompleted,
+/// choices that can't be converted are dropped.
+#[derive(Clone, Debug, PartialEq)]
+pub enum AutoCompleteValue {
+    String(String),
+    Int(i64),
+    Number(f64),
+}
+
+impl AutoCompleteValue {
+    /// Converts the value to match the given application command option type.
+    pub(crate) fn coerce(self, option_type: u32) -> Option<Self> {
+        Some(match (option_type, self) {
+            (4, Self::String(s)) => Self::Int(s.trim().parse().ok()?),
+            (4, Self::Number(n)) if n.fract() == 0.0 => Self::Int(n as i64),
+            (4, Self::Number(_)) => return None,
+            (10, Self::String(s)) => Self::Number(s.trim().parse().ok()?),
+            (10, Self::Int(i)) => Self::Number(i as f64),
+            (4 | 10, value) => value,
+            (_, Self::Int(i)) => Self::String(i.to_string()),
+            (_, Self::Number(n)) => Self::String(n.to_string()),
+            (_, value) => value,
+        })
+    }
+}
+
+impl Default for AutoCompleteValue {
+    fn default() -> Self {
+        Self::String(String::new())
+    }
+}
+
+impl SerJson for AutoCompleteValue {
+    fn ser_json(&self, d: usize, s: &mut nanoserde::SerJsonState) {
+        match self {
+            Self::String(value) => value.ser_json(d, s),
+            Self::Int(value) => value.ser_json(d, s),
+            Self::Number(value) => value.ser_json(d, s),
+        }
+    }
+}
+
+impl DeJson for AutoCompleteValue {
+    fn de_json(
+        s: &mut nanoserde::DeJsonState,
+        i: &mut std::str::Chars,
+    ) -> Result<Self, nanoserde::DeJsonErr> {
+        let value = match s.tok {
+            nanoserde::DeJsonTok::U64(value) => Self::Int(value as i64),
+            nanoserde::DeJsonTok::I64(value) => Self::Int(value),
+            nanoserde::DeJsonTok::F64(value) => Self::Number(value),
+            _ => Self::String(s.as_string()?),
+        };
+
+        s.next_tok(i)?;
+        Ok(value)
+    }
+}
+
+macro_rules! impl_autocomplete_value_from {
+    [ $($ty:ty => $variant:ident),* $(,)? ] => {
+        $(
+            impl From<$ty> for AutoCompleteValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_autocomplete_value_from![
+    String => String,
+    &str => String,
+    i64 => Int,
+    i32 => Int,
+    u32 => Int,
+    f64 => Number,
+    f32 => Number,
+];
+
+impl From<isize> for AutoCompleteValue {
+    fn from(value: isize) -> Self {
+        Self::Int(value as i64)
+    }
 }
 
 /// Represents an interaction response.
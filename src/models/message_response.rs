@@ -76,7 +76,7 @@ impl Message {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// message.reply("Hello, world!").await;
     /// ```
     pub async fn reply(&self, data: impl Into<CreateMessageData>) -> Message {
@@ -91,7 +91,7 @@ impl Message {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// message.send_in_channel("Hello, world!").await;
     /// ```
     pub async fn send_in_channel(&self, data: impl Into<CreateMessageData>) -> Message {
@@ -102,7 +102,7 @@ impl Message {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let channel = message.get_channel().await?;
     /// ```
     pub async fn get_channel(&self) -> Result<Channel, Box<dyn std::error::Error>> {
@@ -118,7 +118,7 @@ impl Message {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let author = message.get_author().await?;
     /// ```
     pub async fn get_author(&self) -> Result<Member, Box<dyn Error>> {
@@ -133,7 +133,7 @@ impl Message {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let guild = message.get_guild().await?;
     /// ```
     pub async fn get_guild(&self) -> Result<Guild, Box<dyn Error>> {
@@ -144,7 +144,7 @@ impl Message {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let referenced_message = message.get_message_reference().await;
     /// ```
     pub async fn get_message_reference(&self) -> Option<Message> {
@@ -159,7 +159,7 @@ impl Message {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// message.delete().await;
     /// ```
     pub async fn delete(&self) -> bool {
@@ -174,7 +174,7 @@ impl Message {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// message.delete_after(tokio::time::Duration::from_secs(10)).await;
     /// ```
     pub async fn delete_after(&self, time: tokio::time::Duration) {
@@ -190,7 +190,7 @@ impl Message {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// message.edit("Edited message").await;
     /// ```
     pub async fn edit(&self, data: impl Into<CreateMessageData>) {
@@ -205,7 +205,7 @@ impl Message {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// message.react("👍").await;
     /// ```
     pub async fn react(&self, emoji: &str) {
@@ -238,7 +238,7 @@ impl CreateMessageData {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let json = message_data.to_json();
     /// ```
     pub fn to_json(&self) -> String {
@@ -269,7 +269,7 @@ impl CreateMessageData {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// message_data.add_components(vec![vec![component1, component2]]);
     /// ```
     pub fn add_components(mut self, components: Vec<Vec<Component>>) -> Self {
@@ -291,7 +291,7 @@ impl From<String> for CreateMessageData {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let message_data: CreateMessageData = "Hello, world!".into();
     /// ```
     fn from(value: String) -> Self {
@@ -311,7 +311,7 @@ impl From<&String> for CreateMessageData {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let message_data: CreateMessageData = (&"Hello, world!".to_string()).into();
     /// ```
     fn from(value: &String) -> Self {
@@ -331,7 +331,7 @@ impl From<&str> for CreateMessageData {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let message_data: CreateMessageData = "Hello, world!".into();
     /// ```
     fn from(value: &str) -> Self {
@@ -351,7 +351,7 @@ impl From<Vec<Embed>> for CreateMessageData {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let message_data: CreateMessageData = vec![embed1, embed2].into();
     /// ```
     fn from(value: Vec<Embed>) -> Self {
@@ -376,7 +376,7 @@ impl From<Embed> for CreateMessageData {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let message_data: CreateMessageData = embed.into();
     /// ```
     fn from(value: Embed) -> Self {
@@ -396,7 +396,7 @@ impl From<AllowedMentions> for CreateMessageData {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let message_data: CreateMessageData = allowed_mentions.into();
     /// ```
     fn from(value: AllowedMentions) -> Self {
@@ -416,7 +416,7 @@ impl From<Vec<Vec<Component>>> for CreateMessageData {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let message_data: CreateMessageData = vec![vec![component1, component2]].into();
     /// ```
     fn from(value: Vec<Vec<Component>>) -> Self {
@@ -453,7 +453,7 @@ impl From<AttachmentPayload> for CreateMessageData {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let message_data: CreateMessageData = attachment_payload.into();
     /// ```
     fn from(value: AttachmentPayload) -> Self {
@@ -473,7 +473,7 @@ impl From<Vec<AttachmentPayload>> for CreateMessageData {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let message_data: CreateMessageData = vec![attachment_payload1, attachment_payload2].into();
     /// ```
     fn from(value: Vec<AttachmentPayload>) -> Self {
@@ -11,6 +11,7 @@ pub mod embed_builder;
 pub mod emoji;
 pub mod guild;
 pub mod guild_create;
+pub mod hybrid_context;
 pub mod interaction;
 pub mod message_response;
 pub mod reaction_response;
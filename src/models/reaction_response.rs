@@ -38,7 +38,7 @@ impl Reaction {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let channel = reaction.get_channel().await?;
     /// ```
     pub async fn get_channel(&self) -> Result<Channel, Box<dyn std::error::Error>> {
@@ -49,7 +49,7 @@ impl Reaction {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let user = reaction.get_user().await?;
     /// ```
     pub async fn get_user(&self) -> Result<User, Box<dyn std::error::Error>> {
@@ -60,7 +60,7 @@ impl Reaction {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let message = reaction.get_message().await?;
     /// ```
     pub async fn get_message(&self) -> Result<Message, Box<dyn std::error::Error>> {
@@ -71,7 +71,7 @@ impl Reaction {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// reaction.remove_reaction().await;
     /// ```
     pub async fn remove_reaction(&self) {
@@ -49,7 +49,7 @@ impl User {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```ignore
     /// let avatar_url = user.get_avatar_url(ImageFormat::PNG, Some(128));
     /// ```
     pub fn get_avatar_url(&self, image_format: ImageFormat, size: Option<u32>) -> Option<String> {
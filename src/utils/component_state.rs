@@ -0,0 +1,305 @@
+use std::io::{Read, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+use flate2::Compression;
+use hmac::{Hmac, Mac};
+use nanoserde::{DeBin, SerBin};
+use sha2::Sha256;
+
+use crate::cache::COMPONENT_STATE_STORE;
+use crate::internals::DescordError;
+
+/// Separates the component id from the encoded state in a custom id.
+pub const STATE_SEPARATOR: char = '~';
+
+/// Discord's limit on custom id length.
+const MAX_CUSTOM_ID_LEN: usize = 100;
+
+const NO_SECRET: &str = "no component secret was set, see `set_component_secret`";
+
+/// Length of the (truncated) HMAC-SHA256 signature.
+const SIGNATURE_LEN: usize = 8;
+
+// Encodings, stored in the first byte of the payload
+const RAW: u8 = 0;
+const DEFLATE: u8 = 1;
+const STORED: u8 = 2;
+
+lazy_static::lazy_static! {
+    static ref SECRET: RwLock<Option<Vec<u8>>> = RwLock::new(None);
+
+    // Seeded with the startup time so keys from a previous run don't point at new state
+    static ref NEXT_STORE_KEY: AtomicU64 = AtomicU64::new(
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_nanos() as u64)
+            .unwrap_or_default()
+    );
+}
+
+/// State that can be carried in a component's custom id.
+///
+/// Implemented for every type that implements `nanoserde::SerBin` and `nanoserde::DeBin`.
+/// The state is compressed and signed with the secret set with
+/// [`set_component_secret`], so users can't forge it.
+///
+/// State that doesn't fit in the custom id is kept in the memory of the process
+/// instead. It is lost when the bot restarts or when too many components are
+/// created, and other instances of the bot can't read it. Keep state small if
+/// components must outlive the process.
+///
+/// # Examples
+///
+/// ```ignore
+/// use nanoserde::{DeBin, SerBin};
+///
+/// #[derive(SerBin, DeBin)]
+/// struct Vote {
+///     poll_id: u64,
+///     option: u8,
+/// }
+///
+/// let custom_id = Vote { poll_id: 1, option: 2 }.custom_id("vote")?;
+///
+/// #[component(id = "vote")]
+/// async fn vote(interaction: Interaction, #[state] vote: Vote) {
+///     // ...
+/// }
+/// ```
+pub trait ComponentState: SerBin + DeBin {
+    /// Encodes the state into a custom id for the component with the given id.
+    ///
+    /// Fails in the same cases as [`encode_state`].
+    fn custom_id(&self, id: &str) -> Result<String, DescordError> {
+        encode_state(id, self)
+    }
+}
+
+impl<T: SerBin + DeBin> ComponentState for T {}
+
+/// Sets the secret used to sign component state, required to use [`ComponentState`].
+///
+/// Use the same secret for every instance of the bot that should be able to read
+/// each other's components, changing it invalidates all existing components.
+pub fn set_component_secret(secret: impl Into<Vec<u8>>) {
+    *SECRET.write().unwrap() = Some(secret.into());
+}
+
+/// Encodes a state into a custom id for the component with the given id.
+///
+/// See [`ComponentState`].
+///
+/// Fails if no secret was set with [`set_component_secret`], or if the id
+/// is too long to fit any state in the custom id.
+pub fn encode_state<T: SerBin + ?Sized>(id: &str, state: &T) -> Result<String, DescordError> {
+    let secret =
+        secret().ok_or_else(|| DescordError::InvalidComponentState(NO_SECRET.to_string()))?;
+
+    let bytes = state.serialize_bin();
+
+    let mut payload = vec![RAW];
+    payload.extend_from_slice(&bytes);
+
+    let compressed = compress(&bytes);
+    if compressed.len() < bytes.len() {
+        payload = vec![DEFLATE];
+        payload.extend(compressed);
+    }
+
+    let encoded_len = |payload_len: usize| {
+        let len = payload_len + SIGNATURE_LEN;
+        id.len() + STATE_SEPARATOR.len_utf8() + (len * 4).div_ceil(3)
+    };
+
+    if encoded_len(payload.len()) > MAX_CUSTOM_ID_LEN {
+        // the key of the stored state takes 8 bytes
+        if encoded_len(1 + 8) > MAX_CUSTOM_ID_LEN {
+            return Err(DescordError::InvalidComponentState(format!(
+                "the component id `{id}` is too long to carry state"
+            )));
+        }
+
+        let key = NEXT_STORE_KEY.fetch_add(1, Ordering::Relaxed);
+        COMPONENT_STATE_STORE.lock().unwrap().put(key, bytes);
+
+        payload = vec![STORED];
+        payload.extend_from_slice(&key.to_le_bytes());
+    }
+
+    let signature = mac(&secret, id, &payload).finalize().into_bytes();
+    payload.extend_from_slice(&signature[..SIGNATURE_LEN]);
+
+    Ok(format!(
+        "{id}{STATE_SEPARATOR}{}",
+        URL_SAFE_NO_PAD.encode(payload)
+    ))
+}
+
+/// Decodes the state from a custom id created with [`encode_state`].
+pub fn decode_state<T: DeBin>(custom_id: &str) -> Result<T, DescordError> {
+    let invalid = |reason: &str| DescordError::InvalidComponentState(reason.to_string());
+
+    let (_, payload) = verify_state(custom_id)?;
+    let bytes = match (payload[0], &payload[1..]) {
+        (RAW, bytes) => bytes.to_vec(),
+        (DEFLATE, compressed) => {
+            decompress(compressed).ok_or_else(|| invalid("failed to decompress the state"))?
+        }
+
+        (STORED, key) => {
+            let key = u64::from_le_bytes(key.try_into().map_err(|_| invalid("malformed state"))?);
+
+            COMPONENT_STATE_STORE
+                .lock()
+                .unwrap()
+                .get(&key)
+                .cloned()
+                .ok_or_else(|| invalid("the state has expired"))?
+        }
+
+        _ => return Err(invalid("unknown state encoding")),
+    };
+
+    T::deserialize_bin(&bytes).map_err(|e| invalid(&e.to_string()))
+}
+
+/// Returns the custom id without its state, or the whole custom id if it doesn't carry valid state.
+pub(crate) fn strip_state(custom_id: &str) -> &str {
+    verify_state(custom_id).map_or(custom_id, |(id, _)| id)
+}
+
+/// Splits a custom id into the component id and the signed payload, without the signature.
+fn verify_state(custom_id: &str) -> Result<(&str, Vec<u8>), DescordError> {
+    let invalid = |reason: &str| DescordError::InvalidComponentState(reason.to_string());
+
+    // the encoded state never contains the separator, the id might
+    let (id, encoded) = custom_id
+        .rsplit_once(STATE_SEPARATOR)
+        .ok_or_else(|| invalid("the custom id doesn't contain any state"))?;
+
+    let mut payload = URL_SAFE_NO_PAD
+        .decode(encoded)
+        .map_err(|_| invalid("malformed state"))?;
+
+    if payload.len() < 1 + SIGNATURE_LEN {
+        return Err(invalid("malformed state"));
+    }
+
+    let secret = secret().ok_or_else(|| invalid(NO_SECRET))?;
+    let signature = payload.split_off(payload.len() - SIGNATURE_LEN);
+    if mac(&secret, id, &payload)
+        .verify_truncated_left(&signature)
+        .is_err()
+    {
+        return Err(invalid("the state signature doesn't match"));
+    }
+
+    Ok((id, payload))
+}
+
+/// Returns the secret set with [`set_component_secret`].
+fn secret() -> Option<Vec<u8>> {
+    SECRET
+        .read()
+        .unwrap()
+        .clone()
+        .filter(|secret| !secret.is_empty())
+}
+
+fn mac(secret: &[u8], id: &str, payload: &[u8]) -> Hmac<Sha256> {
+    // HMAC accepts keys of any length
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).unwrap();
+    mac.update(id.as_bytes());
+    mac.update(&[0]);
+    mac.update(payload);
+    mac
+}
+
+fn compress(bytes: &[u8]) -> Vec<u8> {
+    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::best());
+    encoder.write_all(bytes).unwrap();
+    encoder.finish().unwrap()
+}
+
+fn decompress(bytes: &[u8]) -> Option<Vec<u8>> {
+    let mut decompressed = vec![];
+    DeflateDecoder::new(bytes)
+        .read_to_end(&mut decompressed)
+        .ok()?;
+
+    Some(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state_too_big() -> Vec<u64> {
+        (0..64).collect()
+    }
+
+    fn with_secret() {
+        set_component_secret("test secret");
+    }
+
+    #[test]
+    fn round_trips_state() {
+        with_secret();
+
+        let custom_id = encode_state("vote", &(7u64, String::from("yes"))).unwrap();
+        assert!(custom_id.starts_with("vote~"));
+        assert_eq!(strip_state(&custom_id), "vote");
+        assert_eq!(
+            decode_state::<(u64, String)>(&custom_id).unwrap(),
+            (7, String::from("yes"))
+        );
+    }
+
+    #[test]
+    fn keeps_separators_in_the_id() {
+        with_secret();
+
+        let custom_id = encode_state("tag:a~b", &1u8).unwrap();
+        assert_eq!(strip_state(&custom_id), "tag:a~b");
+        assert_eq!(decode_state::<u8>(&custom_id).unwrap(), 1);
+        assert_eq!(strip_state("tag:a~b"), "tag:a~b");
+    }
+
+    #[test]
+    fn stores_large_state() {
+        with_secret();
+
+        let state = vec![0xabcdu64; 64];
+        let custom_id = encode_state("big", &state).unwrap();
+        assert!(custom_id.len() <= MAX_CUSTOM_ID_LEN);
+        assert_eq!(decode_state::<Vec<u64>>(&custom_id).unwrap(), state);
+    }
+
+    #[test]
+    fn rejects_tampered_state() {
+        with_secret();
+
+        let custom_id = encode_state("vote", &7u64).unwrap();
+
+        // state signed for another component
+        let (_, encoded) = custom_id.split_once(STATE_SEPARATOR).unwrap();
+        assert!(decode_state::<u64>(&format!("poll~{encoded}")).is_err());
+
+        // modified payload
+        let mut payload = URL_SAFE_NO_PAD.decode(encoded).unwrap();
+        payload[1] ^= 1;
+        let tampered = format!("vote~{}", URL_SAFE_NO_PAD.encode(payload));
+        assert!(decode_state::<u64>(&tampered).is_err());
+        assert_eq!(strip_state(&tampered), tampered);
+
+        assert!(decode_state::<u64>("vote").is_err());
+        assert!(encode_state(&"x".repeat(90), &state_too_big()).is_err());
+        assert!(decode_state::<u64>("vote~not base64!").is_err());
+    }
+}
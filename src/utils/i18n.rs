@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::RwLock;
+
+use json::JsonValue;
+
+/// Locale used when a string is missing in the requested locale.
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+lazy_static::lazy_static! {
+    // locale -> (key -> translated string)
+    static ref TRANSLATIONS: RwLock<HashMap<String, HashMap<String, String>>> =
+        RwLock::new(HashMap::new());
+}
+
+/// Loads every `<locale>.json` file in a directory as translations.
+///
+/// The file name is used as the locale (e.g. `de.json`, `es-ES.json`), it must be
+/// one of the [locales supported by discord](https://discord.com/developers/docs/reference#locales).
+/// Nested objects are flattened using `.` as the separator, so
+/// `{ "greet": { "hello": "Hallo" } }` is available under `greet.hello`.
+///
+/// Slash command names and descriptions are picked up from the following keys:
+/// `commands.<command>.name`, `commands.<command>.description`,
+/// `commands.<command>.options.<option>.name` and
+/// `commands.<command>.options.<option>.description`.
+pub fn load_translations(dir: impl AsRef<Path>) -> Result<(), Box<dyn std::error::Error>> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+            continue;
+        }
+
+        let Some(locale) = path.file_stem().and_then(|stem| stem.to_str()) else {
+            continue;
+        };
+
+        let parsed = json::parse(&std::fs::read_to_string(&path)?)?;
+        let mut strings = HashMap::new();
+        flatten(&parsed, String::new(), &mut strings);
+
+        add_translations(locale, strings);
+    }
+
+    Ok(())
+}
+
+/// Adds translations for a locale, replacing existing keys.
+pub fn add_translations(locale: &str, strings: HashMap<String, String>) {
+    TRANSLATIONS
+        .write()
+        .unwrap()
+        .entry(locale.to_string())
+        .or_default()
+        .extend(strings);
+}
+
+/// Looks up a string in the given locale.
+///
+/// Falls back to the base language (`es` for `es-ES`) and then
+/// to [`DEFAULT_LOCALE`], returns `None` if the key is not found anywhere.
+pub fn translate(locale: &str, key: &str) -> Option<String> {
+    translate_exact(locale, key).or_else(|| translate_exact(DEFAULT_LOCALE, key))
+}
+
+/// Looks up a string in the given locale or its base language, without
+/// falling back to [`DEFAULT_LOCALE`].
+pub fn translate_exact(locale: &str, key: &str) -> Option<String> {
+    let translations = TRANSLATIONS.read().unwrap();
+    let base_language = locale.split('-').next().unwrap_or(locale);
+
+    [locale, base_language]
+        .into_iter()
+        .find_map(|locale| translations.get(locale)?.get(key).cloned())
+}
+
+/// Returns the translations of a key in every locale it is available in.
+pub(crate) fn localizations_of(key: &str) -> HashMap<String, String> {
+    TRANSLATIONS
+        .read()
+        .unwrap()
+        .iter()
+        .filter_map(|(locale, strings)| Some((locale.clone(), strings.get(key)?.clone())))
+        .collect()
+}
+
+fn flatten(value: &JsonValue, prefix: String, out: &mut HashMap<String, String>) {
+    match value {
+        JsonValue::Object(object) => {
+            for (key, value) in object.iter() {
+                let key = if prefix.is_empty() {
+                    key.to_string()
+                } else {
+                    format!("{prefix}.{key}")
+                };
+
+                flatten(value, key, out);
+            }
+        }
+
+        JsonValue::Null => {}
+        value => {
+            out.insert(prefix, value.to_string());
+        }
+    }
+}
@@ -1,9 +1,13 @@
+mod component_state;
+mod i18n;
 mod permissions;
 mod rest;
 mod slash_command;
 
 use rest as rest_api;
 
+pub use component_state::*;
+pub use i18n::*;
 pub use permissions::*;
 pub use rest_api::*;
 pub(crate) mod slash {
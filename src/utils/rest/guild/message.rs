@@ -73,6 +73,9 @@ pub async fn react(channel_id: &str, message_id: &str, emoji: &str) {
 
 /// Send (or reply to) a message to a channel.
 ///
+/// When a command runs again because its message was edited,
+/// its previous reply is edited instead of sending a new one.
+///
 /// # Arguments
 /// `channel_id` - The ID of the channel to send the message to.
 /// `reference_message_id` - The ID of the message to reply to, `None` if not replying.
@@ -84,6 +87,15 @@ pub async fn send(
 ) -> Message {
     let data: CreateMessageData = data.into();
 
+    if let Some(message_id) = reference_message_id {
+        if let Some(reply_id) = crate::internals::previous_reply(message_id) {
+            if let Some(reply) = edit_reply(channel_id, &reply_id, data.clone()).await {
+                crate::internals::record_reply(message_id, &reply.id);
+                return reply;
+            }
+        }
+    }
+
     let mut body = json::parse(&data.to_json()).unwrap();
 
     if let Some(message_id) = reference_message_id {
@@ -120,21 +132,57 @@ pub async fn send(
     let response = client.multipart(multipart).send().await.unwrap();
     let response_text = response.text().await.unwrap();
 
-    Message::deserialize_json(&response_text.to_string()).unwrap_or_else(|e| {
+    let message = Message::deserialize_json(&response_text.to_string()).unwrap_or_else(|e| {
         panic!(
             "Failed to send message, error: {}",
             json::parse(&response_text).unwrap()["message"]
                 .as_str()
                 .unwrap()
         )
-    })
+    });
+
+    if let Some(message_id) = reference_message_id {
+        crate::internals::record_reply(message_id, &message.id);
+    }
+
+    message
+}
+
+/// Edits the reply to a previous run of a command, `None` if it can't be edited, e.g. because it was deleted.
+///
+/// Embeds, components and attachments the new reply doesn't have are removed.
+async fn edit_reply(channel_id: &str, reply_id: &str, data: CreateMessageData) -> Option<Message> {
+    let url = format!("channels/{channel_id}/messages/{reply_id}");
+
+    // edits keep the fields they don't mention, and attachments not listed are removed
+    let mut body = json::parse(&data.to_json()).ok()?;
+    for field in ["embeds", "components"] {
+        if body[field].is_null() {
+            body[field] = json::array![];
+        }
+    }
+
+    body["attachments"] = data
+        .attachments
+        .iter()
+        .enumerate()
+        .map(|(idx, attachment)| json::object! { id: idx, filename: attachment.file_name.as_str() })
+        .collect::<Vec<_>>()
+        .into();
+
+    let response =
+        request_with_attachments(Method::PATCH, &url, json::stringify(body), data.attachments)
+            .await
+            .ok()?;
+
+    Message::deserialize_json(&response.text().await.ok()?).ok()
 }
 
 pub async fn get_message_multipart(
     channel_id: &str,
     attachments: Vec<AttachmentPayload>,
     payload_json: Option<String>,
-) -> Result<Form, Box<dyn std::error::Error>> {
+) -> Result<Form, std::io::Error> {
     let mut form = Form::new();
 
     if let Some(payload_json) = payload_json {
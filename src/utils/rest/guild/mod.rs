@@ -23,6 +23,28 @@ pub async fn fetch_application_commands(bot_id: &str) -> Vec<ApplicationCommand>
     .await
     .unwrap();
 
+    // Choice values can be strings or numbers, store them all as strings
+    let resp = match json::parse(&resp) {
+        Ok(mut commands) => {
+            fn stringify_choices(options: &mut JsonValue) {
+                for option in options.members_mut() {
+                    for choice in option["choices"].members_mut() {
+                        choice["value"] = JsonValue::String(choice["value"].to_string());
+                    }
+
+                    stringify_choices(&mut option["options"]);
+                }
+            }
+
+            for command in commands.members_mut() {
+                stringify_choices(&mut command["options"]);
+            }
+
+            commands.dump()
+        }
+        Err(_) => resp,
+    };
+
     DeJson::deserialize_json(&resp).unwrap_or_else(|e| {
         log::error!("Failed to deserialize JSON: {}", e);
         vec![]
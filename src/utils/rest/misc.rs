@@ -82,6 +82,61 @@ async fn request_int<T: AsRef<str>>(
     response
 }
 
+/// Sends a multipart request, used for uploading attachments.
+pub async fn request_multipart<T: AsRef<str>>(
+    method: Method,
+    endpoint: T,
+    form: reqwest::multipart::Form,
+) -> Result<Response, Error> {
+    let mut headers = get_headers();
+    headers.remove("Content-Type");
+
+    Client::new()
+        .request(method, format!("{}/{}", API, endpoint.as_ref()))
+        .headers(headers)
+        .multipart(form)
+        .send()
+        .await
+}
+
+/// Sends a message payload, as multipart if there are attachments to upload.
+///
+/// Unsuccessful responses are turned into errors.
+pub async fn request_with_attachments<T: AsRef<str>>(
+    method: Method,
+    endpoint: T,
+    body: String,
+    attachments: Vec<AttachmentPayload>,
+) -> Result<Response, DescordError> {
+    let response = if attachments.is_empty() {
+        request(method, endpoint.as_ref(), Some(&body)).await
+    } else {
+        let form = get_message_multipart("", attachments, Some(body)).await?;
+        request_multipart(method, endpoint, form).await?
+    };
+
+    check_response(response).await
+}
+
+/// Turns an unsuccessful response into a [`DescordError::Api`] error.
+pub async fn check_response(response: Response) -> Result<Response, DescordError> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let body = response.text().await?;
+    let message = json::parse(&body)
+        .ok()
+        .and_then(|json| json["message"].as_str().map(str::to_string))
+        .unwrap_or(body);
+
+    Err(DescordError::Api {
+        status: status.as_u16(),
+        message,
+    })
+}
+
 pub async fn update_rate_limit_info(headers: &HeaderMap<HeaderValue>, bucket: &str) {
     let remaining = headers
         .get("x-ratelimit-remaining")
@@ -144,6 +199,24 @@ pub async fn fetch_bot_id() -> String {
         .to_string()
 }
 
+/// Returns the ids of the application's owner, or the members of its team.
+pub async fn fetch_application_owners() -> Result<Vec<String>, DescordError> {
+    let response = request(Method::GET, "oauth2/applications/@me", None).await;
+    let body = check_response(response).await?.text().await?;
+    let application = json::parse(&body).unwrap_or(JsonValue::Null);
+
+    let team = application["team"]["members"]
+        .members()
+        .filter_map(|member| member["user"]["id"].as_str());
+
+    Ok(application["owner"]["id"]
+        .as_str()
+        .into_iter()
+        .chain(team)
+        .map(str::to_string)
+        .collect())
+}
+
 /// Returns a new DM channel with a user (or return
 /// an existing one). Returns a `DirectMessageChannel` object.
 pub async fn fetch_dm(user_id: &str) -> DirectMessageChannel {
@@ -9,10 +9,12 @@ pub use misc::*;
 use crate::cache::*;
 use crate::client::TOKEN;
 use crate::consts::API;
+use crate::internals::DescordError;
 use std::collections::HashMap;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use crate::models::application_command::ApplicationCommand;
+use crate::models::attachment::AttachmentPayload;
 use crate::models::channel::Channel;
 use crate::models::dm_channel::DirectMessageChannel;
 use crate::models::message_response::CreateMessageData;
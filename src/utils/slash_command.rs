@@ -5,6 +5,7 @@ use reqwest::Method;
 
 use super::*;
 use crate::consts::permissions as perms;
+use crate::consts::IntegrationType;
 use crate::internals::*;
 
 use crate::models::application_command::{ApplicationCommand, ApplicationCommandOption};
@@ -13,35 +14,78 @@ fn map_param_type_to_u32(param_type: &ParamType) -> u32 {
     match param_type {
         ParamType::String => 3,
         ParamType::Int => 4,
+        ParamType::Number => 10,
+        ParamType::Bool => 5,
         ParamType::User => 6,
         ParamType::Channel => 7,
         _ => 3,
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+/// Localizations loaded from translation files, overridden by the ones set in the macro.
+fn merge_localizations(key: &str, local: &HashMap<String, String>) -> HashMap<String, String> {
+    let mut localizations = super::localizations_of(key);
+    localizations.extend(local.clone());
+    localizations
+}
+
+/// Normalizes the values of integer and number choices, `2.0` and `2` are the same choice.
+fn normalize_choices(
+    r#type: u32,
+    choices: impl Iterator<Item = (String, String)>,
+) -> Vec<(String, String)> {
+    choices
+        .map(|(name, value)| match (r#type, value.parse::<f64>()) {
+            (4 | 10, Ok(number)) => (name, number.to_string()),
+            _ => (name, value),
+        })
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
 struct CommandOption {
     name: String,
     description: String,
     r#type: u32,
     required: bool,
     autocomplete: bool,
+    name_localizations: HashMap<String, String>,
+    description_localizations: HashMap<String, String>,
+    choices: Vec<(String, String)>,
+    channel_types: Vec<u32>,
+    min_value: Option<f64>,
+    max_value: Option<f64>,
+    min_length: Option<u32>,
+    max_length: Option<u32>,
 }
 
 impl CommandOption {
-    fn from_local(
-        name: &str,
-        description: &str,
-        type_: &ParamType,
-        required: bool,
-        autocomplete: Option<AutoCompleteFn>,
-    ) -> Self {
+    fn from_local(command: &SlashCommand, idx: usize) -> Self {
+        let constraints = &command.fn_param_constraints[idx];
+        let localizations = &command.fn_param_localizations[idx];
+        let name = command.option_name(idx);
+        let key = format!("commands.{}.options.{name}", command.name);
+
         CommandOption {
+            description: command.fn_param_descriptions[idx].clone(),
+            r#type: map_param_type_to_u32(&command.fn_sig[idx]),
+            required: !command.optional_params[idx],
+            autocomplete: command.fn_param_autocomplete[idx].is_some(),
+            name_localizations: merge_localizations(&format!("{key}.name"), &localizations.names),
+            description_localizations: merge_localizations(
+                &format!("{key}.description"),
+                &localizations.descriptions,
+            ),
+            choices: normalize_choices(
+                map_param_type_to_u32(&command.fn_sig[idx]),
+                constraints.choices.iter().cloned(),
+            ),
             name: name.to_string(),
-            description: description.to_string(),
-            r#type: map_param_type_to_u32(type_),
-            required,
-            autocomplete: autocomplete.is_some(),
+            channel_types: vec![],
+            min_value: constraints.min_value,
+            max_value: constraints.max_value,
+            min_length: constraints.min_length,
+            max_length: constraints.max_length,
         }
     }
 
@@ -52,78 +96,185 @@ impl CommandOption {
             r#type: opt.type_,
             required: opt.required.unwrap_or(false),
             autocomplete: opt.autocomplete.unwrap_or(false),
+            name_localizations: opt.name_localizations.clone().unwrap_or_default(),
+            description_localizations: opt.description_localizations.clone().unwrap_or_default(),
+            choices: normalize_choices(
+                opt.type_,
+                opt.choices
+                    .iter()
+                    .flatten()
+                    .map(|choice| (choice.name.clone(), choice.value.clone())),
+            ),
+            channel_types: opt.channel_types.clone().unwrap_or_default(),
+            min_value: opt.min_value,
+            max_value: opt.max_value,
+            min_length: opt.min_length.map(|i| i as u32),
+            max_length: opt.max_length.map(|i| i as u32),
         }
     }
+
+    fn to_json(&self) -> json::JsonValue {
+        let choices = self
+            .choices
+            .iter()
+            .map(|(name, value)| {
+                // integer and number options expect numeric choice values
+                let value = match self.r#type {
+                    4 | 10 => json::parse(value).unwrap_or_else(|_| value.as_str().into()),
+                    _ => value.as_str().into(),
+                };
+
+                json::object! { name: name.as_str(), value: value }
+            })
+            .collect::<Vec<_>>();
+
+        let mut option = json::object! {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            type: self.r#type,
+            required: self.required,
+            autocomplete: self.autocomplete,
+            name_localizations: self.name_localizations.clone(),
+            description_localizations: self.description_localizations.clone(),
+        };
+
+        if !choices.is_empty() {
+            option["choices"] = choices.into();
+        }
+
+        if !self.channel_types.is_empty() {
+            option["channel_types"] = self.channel_types.clone().into();
+        }
+
+        if let Some(min_value) = self.min_value {
+            option["min_value"] = min_value.into();
+        }
+
+        if let Some(max_value) = self.max_value {
+            option["max_value"] = max_value.into();
+        }
+
+        if let Some(min_length) = self.min_length {
+            option["min_length"] = min_length.into();
+        }
+
+        if let Some(max_length) = self.max_length {
+            option["max_length"] = max_length.into();
+        }
+
+        option
+    }
 }
 
-pub async fn register_slash_commands(commands: Vec<SlashCommand>) -> HashMap<String, SlashCommand> {
-    let mut slash_commands = HashMap::new();
-    let bot_id = fetch_bot_id().await;
-    let registered_commands = fetch_application_commands(&bot_id).await;
+/// Everything about a slash command that is stored on discord's side.
+///
+/// Used both to build the registration payload and to detect whether a
+/// registered command is outdated.
+#[derive(Debug, PartialEq)]
+struct CommandDefinition {
+    name: String,
+    description: String,
+    default_member_permissions: Option<String>,
+    nsfw: bool,
+    contexts: Option<Vec<u32>>,
+    integration_types: Vec<u32>,
+    name_localizations: HashMap<String, String>,
+    description_localizations: HashMap<String, String>,
+    options: Vec<CommandOption>,
+}
 
-    for local_command in &commands {
+impl CommandDefinition {
+    fn from_local(command: &SlashCommand) -> Self {
         let mut permissions: u64 = 0;
-        for permission in &local_command.permissions {
-            let permission = perms::parse(permission).expect("unknown permission name");
-            permissions |= permission;
+        for permission in &command.permissions {
+            permissions |= perms::parse(permission).expect("unknown permission name");
         }
 
-        let local_options = local_command
-            .fn_sig
-            .iter()
-            .enumerate()
-            .map(|(i, param_type)| {
-                CommandOption::from_local(
-                    &local_command.fn_param_names[i],
-                    &local_command.fn_param_descriptions[i],
-                    param_type,
-                    !local_command.optional_params[i],
-                    local_command.fn_param_autocomplete[i],
-                )
-            })
-            .collect::<Vec<_>>();
-        let options = json::JsonValue::Array(
-            local_options
+        CommandDefinition {
+            name: command.name.clone(),
+            description: command.description.clone(),
+            default_member_permissions: (permissions != 0).then(|| permissions.to_string()),
+            nsfw: command.nsfw,
+            contexts: (!command.contexts.is_empty())
+                .then(|| command.contexts.iter().map(|&i| i as u32).collect()),
+            integration_types: if command.integration_types.is_empty() {
+                vec![IntegrationType::GuildInstall as u32]
+            } else {
+                command
+                    .integration_types
+                    .iter()
+                    .map(|&i| i as u32)
+                    .collect()
+            },
+            name_localizations: merge_localizations(
+                &format!("commands.{}.name", command.name),
+                &command.localizations.names,
+            ),
+            description_localizations: merge_localizations(
+                &format!("commands.{}.description", command.name),
+                &command.localizations.descriptions,
+            ),
+            options: (0..command.fn_sig.len())
+                .map(|idx| CommandOption::from_local(command, idx))
+                .collect(),
+        }
+    }
+
+    fn from_registered(command: &ApplicationCommand) -> Self {
+        CommandDefinition {
+            name: command.name.clone(),
+            description: command.description.clone(),
+            default_member_permissions: command.default_member_permissions.clone(),
+            nsfw: command.nsfw.unwrap_or(false),
+            contexts: command.contexts.clone(),
+            integration_types: command.integration_types.clone().unwrap_or_default(),
+            name_localizations: command.name_localizations.clone().unwrap_or_default(),
+            description_localizations: command
+                .description_localizations
+                .clone()
+                .unwrap_or_default(),
+            options: command
+                .options
                 .iter()
-                .map(|opt| {
-                    json::object! {
-                        "name" => opt.name.clone(),
-                        "description" => opt.description.clone(),
-                        "type" => opt.r#type,
-                        "required" => opt.required,
-                        "autocomplete" => opt.autocomplete
-                    }
-                })
+                .flatten()
+                .map(CommandOption::from_registered)
                 .collect(),
-        );
+        }
+    }
+
+    fn to_json(&self) -> json::JsonValue {
+        json::object! {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            options: self.options.iter().map(CommandOption::to_json).collect::<Vec<_>>(),
+            default_member_permissions: self.default_member_permissions.clone(),
+            nsfw: self.nsfw,
+            contexts: self.contexts.clone(),
+            integration_types: self.integration_types.clone(),
+            name_localizations: self.name_localizations.clone(),
+            description_localizations: self.description_localizations.clone(),
+        }
+    }
+}
+
+pub async fn register_slash_commands(commands: Vec<SlashCommand>) -> HashMap<String, SlashCommand> {
+    let mut slash_commands = HashMap::new();
+    let bot_id = fetch_bot_id().await;
+    let registered_commands = fetch_application_commands(&bot_id).await;
+
+    for local_command in &commands {
+        let local_definition = CommandDefinition::from_local(local_command);
 
         // If the command exists in the fetched commands
         if let Some(registered_command) = registered_commands
             .iter()
             .find(|&cmd| cmd.name.as_str() == local_command.name)
         {
-            let registered_options = registered_command
-                .options
-                .as_ref()
-                .unwrap_or(&vec![])
-                .iter()
-                .map(CommandOption::from_registered)
-                .collect::<Vec<_>>();
-
-            if local_options != registered_options {
+            if local_definition != CommandDefinition::from_registered(registered_command) {
                 request(
                     Method::PATCH,
                     format!("applications/{}/commands/{}", bot_id, registered_command.id).as_str(),
-                    Some(
-                        json::object! {
-                            name: local_command.name.clone(),
-                            description: local_command.description.clone(),
-                            options: options,
-                            default_member_permissions: permissions.to_string(),
-                        }
-                        .dump()
-                        .as_str(),
-                    ),
+                    Some(local_definition.to_json().dump().as_str()),
                 )
                 .await;
 
@@ -146,15 +297,7 @@ pub async fn register_slash_commands(commands: Vec<SlashCommand>) -> HashMap<Str
             let response = request(
                 Method::POST,
                 format!("applications/{}/commands", bot_id),
-                Some(
-                    json::object! {
-                        name: local_command.name.clone(),
-                        description: local_command.description.clone(),
-                        options: options,
-                        default_member_permissions: permissions.to_string(),
-                    }
-                    .dump(),
-                ),
+                Some(local_definition.to_json().dump()),
             )
             .await
             .text()
@@ -0,0 +1,112 @@
+use std::sync::RwLock;
+use std::time::Duration;
+
+use log::*;
+use nanoserde::{DeJson, SerJson};
+use reqwest::Method;
+
+use super::Handlers;
+use crate::consts::InteractionType;
+use crate::internals::{guard, handle_error};
+use crate::models::interaction::{Interaction, InteractionAutoCompleteChoices};
+use crate::utils::request;
+use crate::DescordResult;
+
+lazy_static::lazy_static! {
+    // How long a slash command or component handler can run before it is deferred
+    static ref AUTO_DEFER_AFTER: RwLock<Option<Duration>> = RwLock::new(Some(Duration::from_secs(2)));
+}
+
+/// Sets how long a slash command or component handler can run before the interaction is deferred, `None` disables it.
+pub(crate) fn set_auto_defer(after: Option<Duration>) {
+    *AUTO_DEFER_AFTER.write().unwrap() = after;
+}
+
+/// Parses an interaction object, as sent through the gateway or an interactions endpoint.
+pub(crate) fn parse_interaction(
+    mut json: json::JsonValue,
+) -> Result<Interaction, nanoserde::DeJsonErr> {
+    // A band-aid solution
+    if let json::JsonValue::Array(options) = &mut json["data"]["options"] {
+        for option in options {
+            option["value"] = json::JsonValue::String(option["value"].to_string());
+        }
+    }
+
+    Interaction::deserialize_json(&json.dump())
+}
+
+/// Routes an interaction to its slash command, component or autocomplete handler.
+pub(crate) async fn dispatch_interaction(interaction: &Interaction, handlers: &Handlers) {
+    if interaction.type_ == InteractionType::ApplicationCommand as u32 {
+        let command_id = interaction.data.as_ref().and_then(|d| d.id.as_ref());
+        if let Some(command) = command_id.and_then(|id| handlers.slash_commands.get(id)) {
+            let call = guard(command.timeout, command.call(interaction.clone()));
+            if let Err(e) = with_auto_defer(interaction, call).await {
+                handle_error(command.invocation(interaction), e).await;
+            };
+        }
+    } else if interaction.type_ == InteractionType::MessageComponent as u32
+        || interaction.type_ == InteractionType::ModalSubmit as u32
+    {
+        let custom_id = interaction
+            .data
+            .as_ref()
+            .and_then(|d| d.custom_id.as_deref());
+        if let Some((handler, args)) = custom_id.and_then(|id| handlers.component_handlers.find(id))
+        {
+            let call = guard(None, handler.call(interaction.clone(), args));
+            if let Err(e) = with_auto_defer(interaction, call).await {
+                handle_error(handler.invocation(interaction), e).await;
+            }
+        }
+    } else if interaction.type_ == InteractionType::ApplicationCommandAutocomplete as u32 {
+        let command_id = interaction.data.as_ref().and_then(|d| d.id.as_ref());
+        let choices = match command_id.and_then(|id| handlers.slash_commands.get(id)) {
+            Some(slash_command) => slash_command.autocomplete(interaction.clone()).await,
+            None => {
+                warn!("Received autocomplete request for an unknown slash command");
+                vec![]
+            }
+        };
+
+        let body = InteractionAutoCompleteChoices::new(choices).serialize_json();
+
+        #[cfg(feature = "http-interactions")]
+        if let Some(sender) = crate::http::take_response_channel(&interaction.id) {
+            let _ = sender.send(body);
+            return;
+        }
+
+        request(
+            Method::POST,
+            &format!(
+                "/interactions/{}/{}/callback",
+                interaction.id, interaction.token
+            ),
+            Some(&body),
+        )
+        .await;
+    }
+}
+
+/// Runs a handler, deferring the interaction if it doesn't respond in time.
+async fn with_auto_defer(
+    interaction: &Interaction,
+    call: impl std::future::Future<Output = DescordResult>,
+) -> DescordResult {
+    tokio::pin!(call);
+
+    // Discord only waits 3 seconds for the initial response
+    let auto_defer_after = *AUTO_DEFER_AFTER.read().unwrap();
+    match auto_defer_after {
+        Some(after) => tokio::select! {
+            result = &mut call => result,
+            _ = tokio::time::sleep(after) => {
+                interaction.auto_defer().await;
+                call.await
+            }
+        },
+        None => call.await,
+    }
+}
@@ -1,4 +1,6 @@
 mod websocket_manager;
 
+pub(crate) mod interactions;
+
 pub mod payload;
 pub use websocket_manager::*;
@@ -1,5 +1,6 @@
 // std
 use std::collections::HashMap;
+use std::panic::AssertUnwindSafe;
 use std::str::FromStr;
 use std::sync::Arc;
 use std::time::Duration;
@@ -18,8 +19,7 @@ use crate::{internals::*, utils};
 
 // models
 use crate::models::interaction::{
-    Interaction, InteractionAutoCompleteChoice, InteractionAutoCompleteChoicePlaceholder,
-    InteractionAutoCompleteChoices, InteractionResponsePayload,
+    Interaction, InteractionAutoCompleteChoicePlaceholder, InteractionResponsePayload,
 };
 use crate::models::ready_response::ReadyResponse;
 use crate::models::*;
@@ -41,12 +41,13 @@ use tokio_tungstenite::MaybeTlsStream;
 use tokio_tungstenite::{connect_async, WebSocketStream};
 
 use futures_util::stream::{SplitSink, SplitStream};
-use futures_util::{future, pin_mut, SinkExt, StreamExt};
+use futures_util::{future, pin_mut, FutureExt, SinkExt, StreamExt};
 
 use crate::consts::events::Event;
 use crate::consts::opcode::OpCode;
 use crate::consts::{self, payloads, InteractionCallbackType, InteractionType};
 use crate::utils::{fetch_channel, fetch_guild, fetch_member, request};
+use crate::ws::interactions::{dispatch_interaction, parse_interaction};
 use crate::ws::payload::Payload;
 use crate::Client;
 
@@ -166,9 +167,18 @@ impl WsManager {
                     }
 
                     tokio::spawn(async move {
-                        Self::dispatch_event(payload, seq, handlers)
-                            .await
-                            .expect("Failed to parse json response");
+                        let event = payload.type_name.clone().unwrap_or_default();
+                        let dispatch = Self::dispatch_event(payload, seq, handlers);
+
+                        // handlers are guarded separately, this catches failures while parsing
+                        match AssertUnwindSafe(dispatch).catch_unwind().await {
+                            Ok(Ok(())) => {}
+                            Ok(Err(e)) => error!("Failed to parse the {event} event: {e}"),
+                            Err(panic) => error!(
+                                "Panicked while dispatching the {event} event (sequence {current_seq}): {}",
+                                panic_message(panic.as_ref())
+                            ),
+                        }
                     });
                 }
             } else {
@@ -207,7 +217,7 @@ impl WsManager {
             }
         };
 
-        let data = match event {
+        let data: HandlerValue = match event {
             Event::Ready => {
                 let data = ReadyResponse::deserialize_json(&payload.raw_json).unwrap();
 
@@ -226,68 +236,54 @@ impl WsManager {
                     .await
                     .put(message_data.data.id.clone(), message_data.data.clone());
 
-                if let Some(command_name) = message_data.data.content.split(' ').next() {
-                    if let Some(command_handler_fn) = handlers.commands.get(command_name) {
-                        let mut required_permissions: u64 = 0;
-
-                        for permission in &command_handler_fn.permissions {
-                            required_permissions |= consts::permissions::parse(permission)
-                                .expect("Invalid permission name");
-                        }
-
-                        let msg_id = message_data.data.id.clone();
-                        let channel_id = message_data.data.channel_id.clone();
-
-                        if required_permissions != 0 {
-                            let channel = fetch_channel(&channel_id).await.unwrap();
-                            let guild = fetch_guild(channel.guild_id.as_ref().unwrap())
-                                .await
-                                .unwrap();
-                            let data = message_data.data.clone();
-                            let user_permissions: u64 = Self::fetch_permissions(
-                                data.member.unwrap().roles,
-                                data.author.unwrap().id,
-                                &guild,
-                                Some(&channel),
-                            )
-                            .await;
-
-                            // bypass the role check if user has admin perms
-                            if user_permissions != consts::permissions::ADMINISTRATOR
-                                && user_permissions & required_permissions != required_permissions
-                            {
-                                utils::send(
-                                    &channel_id,
-                                    Some(
-                                    &msg_id),
-                                    "You are missing the required permissions for running this command",
-                                )
-                                    .await;
-
-                                return Ok(());
-                            }
-                        }
-
-                        let handler = command_handler_fn.clone();
-                        if let Err(e) = command_handler_fn.call(message_data.data.clone()).await {
-                            utils::send(&channel_id, Some(&msg_id), e.to_string()).await;
-                        }
+                track_message(&message_data.data);
 
-                        return Ok(());
-                    }
+                let bot_id = BOT_ID.lock().unwrap().clone();
+                let prefixes = resolve_prefixes(&message_data.data).await;
+                if Self::dispatch_command(
+                    &message_data.data,
+                    &prefixes,
+                    bot_id.as_deref(),
+                    &handlers,
+                )
+                .await
+                {
+                    return Ok(());
                 }
 
+                handlers
+                    .commands
+                    .reply_suggestions(&message_data.data, &prefixes, bot_id.as_deref())
+                    .await;
+
                 message_data.data.into()
             }
 
             Event::MessageUpdate => {
                 let message_data = MessageResponse::deserialize_json(&payload.raw_json).unwrap();
 
-                MESSAGE_CACHE
+                let previous = MESSAGE_CACHE
                     .lock()
                     .await
                     .put(message_data.data.id.clone(), message_data.data.clone());
 
+                // resolved embeds update messages too
+                let edited =
+                    previous.is_some_and(|previous| previous.content != message_data.data.content);
+
+                // the `message_update` handler runs for reruns as well
+                if edited && should_rerun(&message_data.data) {
+                    let bot_id = BOT_ID.lock().unwrap().clone();
+                    let prefixes = resolve_prefixes(&message_data.data).await;
+                    Self::dispatch_command(
+                        &message_data.data,
+                        &prefixes,
+                        bot_id.as_deref(),
+                        &handlers,
+                    )
+                    .await;
+                }
+
                 message_data.data.into()
             }
 
@@ -314,12 +310,10 @@ impl WsManager {
                         .get(&Event::MessageDeleteRaw)
                         .cloned()
                     {
-                        let msg_id = data.data.message_id.clone();
-                        let channel_id = data.data.channel_id.clone();
-
                         tokio::spawn(async move {
-                            if let Err(e) = handler.call(data.data.into()).await {
-                                utils::send(&channel_id, Some(&msg_id), e.to_string()).await;
+                            let data: HandlerValue = data.data.into();
+                            if let Err(e) = guard(None, handler.call(data.clone())).await {
+                                handle_error(handler.invocation(&data), e).await;
                             }
                         });
                     }
@@ -376,73 +370,11 @@ impl WsManager {
             }
 
             Event::InteractionCreate => {
-                // A band-aid solution
                 let mut json = json::parse(&payload.raw_json).unwrap();
-                let json =
-                    if let json::JsonValue::Array(options) = &mut json["d"]["data"]["options"] {
-                        for option in options {
-                            option["value"] = json::JsonValue::String(option["value"].to_string());
-                        }
-
-                        json.dump()
-                    } else {
-                        payload.raw_json.clone()
-                    };
-
-                let data = InteractionResponsePayload::deserialize_json(&json).unwrap();
-
-                if data.data.type_ == InteractionType::ApplicationCommand as u32 {
-                    if let Some(d) = &data.data.data {
-                        if let Some(command) = handlers.slash_commands.get(&d.clone().id.unwrap()) {
-                            let handler = command.clone();
-                            if let Err(e) = handler.call(data.data.clone()).await {
-                                data.data.reply(e.to_string(), true).await;
-                            };
-                        }
-                    }
-                } else if data.data.type_ == InteractionType::MessageComponent as u32 {
-                    let id: &str = data.data.data.as_ref().unwrap().custom_id.as_ref().unwrap();
-                    if let Some(component_handler) = handlers.component_handlers.get(id) {
-                        component_handler.call(data.data.clone()).await;
-                    }
-                } else if data.data.type_ == InteractionType::ApplicationCommandAutocomplete as u32
-                {
-                    let slash_command = handlers
-                        .slash_commands
-                        .get(data.data.data.as_ref().unwrap().id.as_ref().unwrap())
-                        .unwrap();
-                    let options = &data.data.data.as_ref().unwrap().options.as_ref().unwrap();
-
-                    for (idx, itm) in options.iter().enumerate() {
-                        if itm.focused.unwrap_or(false) {
-                            // SAFETY: We are sure that the fn_param_autocomplete is not None
-                            let choices = slash_command.fn_param_autocomplete[idx].unwrap()(
-                                itm.value.clone(),
-                            )
-                            .await
-                            .into_iter()
-                            .map(|i| InteractionAutoCompleteChoice {
-                                name: i.clone(),
-                                value: i,
-                            })
-                            .collect();
-
-                            request(
-                                Method::POST,
-                                &format!(
-                                    "/interactions/{}/{}/callback",
-                                    data.data.id, data.data.token
-                                ),
-                                Some(
-                                    &InteractionAutoCompleteChoices::new(choices).serialize_json(),
-                                ),
-                            )
-                            .await;
-                        }
-                    }
-                }
+                let interaction = parse_interaction(json["d"].take()).unwrap();
 
-                data.data.into()
+                dispatch_interaction(&interaction, &handlers).await;
+                interaction.into()
             }
 
             _ => {
@@ -452,13 +384,36 @@ impl WsManager {
         };
 
         if let Some(handler) = handlers.event_handlers.get(&event) {
-            // TODO: pass context data along with the error for error reporting
-            handler.call(data).await;
+            if let Err(e) = guard(None, handler.call(data.clone())).await {
+                handle_error(handler.invocation(&data), e).await;
+            }
         }
 
         Ok(())
     }
 
+    /// Runs the message command a message invokes, returns `false` if it doesn't invoke any.
+    async fn dispatch_command(
+        message: &message_response::Message,
+        prefixes: &[String],
+        bot_id: Option<&str>,
+        handlers: &Handlers,
+    ) -> bool {
+        let Some((command, args)) = handlers.commands.find(&message.content, prefixes, bot_id)
+        else {
+            return false;
+        };
+
+        run_tracked(message, async {
+            if let Err(e) = guard(command.timeout, command.call(message.clone(), args)).await {
+                handle_error(command.invocation(message), e).await;
+            }
+        })
+        .await;
+
+        true
+    }
+
     async fn reconnect(seq: Arc<Mutex<usize>>) -> (SocketWrite, SocketRead) {
         info!("Reopening the connection...");
 
@@ -516,68 +471,13 @@ impl WsManager {
     async fn send_text(&self, msg: String) -> Result<()> {
         self.socket.0.lock().await.send(Message::Text(msg)).await
     }
-
-    async fn fetch_permissions(
-        roles: Vec<String>,
-        id: String,
-        guild: &Guild,
-        channel: Option<&Channel>,
-    ) -> u64 {
-        // Check if member is the guild owner
-        if guild.owner_id == id {
-            return ADMINISTRATOR;
-        }
-
-        // Start with default role permissions or bot-specific permissions
-        let mut base_permissions = guild
-            .default_role()
-            .await
-            .unwrap()
-            .permissions
-            .parse::<u64>()
-            .unwrap();
-
-        // Aggregate permissions from member's roles
-        for role_id in &roles {
-            if let Ok(role) = guild.fetch_role(role_id).await {
-                base_permissions |= role.permissions.parse::<u64>().unwrap();
-            }
-        }
-
-        // Administrator check
-        if base_permissions & ADMINISTRATOR == ADMINISTRATOR {
-            return ADMINISTRATOR;
-        }
-
-        // Apply permission overwrites if channel is provided
-        if let Some(channel) = channel {
-            if let Some(overwrites) = &channel.permission_overwrites {
-                for overwrite in overwrites {
-                    let allow = overwrite.allow.parse::<u64>().unwrap();
-                    let deny = overwrite.deny.parse::<u64>().unwrap();
-
-                    if overwrite.overwrite_type == 1 && overwrite.id == id {
-                        // Member specific overwrites
-                        base_permissions &= !deny;
-                        base_permissions |= allow;
-                    } else if overwrite.overwrite_type == 0 && roles.contains(&overwrite.id) {
-                        // Role specific overwrites
-                        base_permissions &= !deny;
-                        base_permissions |= allow;
-                    }
-                }
-            }
-        }
-
-        base_permissions
-    }
 }
 
 pub struct Handlers {
     pub event_handlers: Arc<HashMap<Event, EventHandler>>,
-    pub commands: Arc<HashMap<String, Command>>,
+    pub commands: Arc<CommandRouter>,
     pub slash_commands: Arc<HashMap<String, SlashCommand>>,
-    pub component_handlers: Arc<HashMap<String, ComponentHandler>>,
+    pub component_handlers: Arc<ComponentRouter>,
 }
 
 impl Clone for Handlers {